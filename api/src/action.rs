@@ -1,9 +1,10 @@
 use std::{collections::HashMap, sync::Arc};
 
 use app::{
-    core::{TournamentManager, WsFailurePayload},
+    core::{TournamentManager, WsFailurePayload, start_practice},
     state::AppState,
 };
+use models::params::tournament::MAX_TEAM_NAME_LENGTH;
 use models::schemas::user::{AuthSchema, TournamentRoomMember};
 use socketioxide::extract::{HttpExtension, SocketRef};
 use tracing::{error, info, warn};
@@ -11,8 +12,67 @@ use uuid::Uuid;
 
 use crate::{decode_noauth, encode_noauth};
 
+/// Builds the member behind a handshake. Guests keep the identity carried by their
+/// `x-noauth-unique` header; new guests get one, returned as the `noauth` token to send back.
+async fn room_member(
+    app_state: &AppState,
+    auth_state: &AuthSchema,
+    socket: &SocketRef,
+    participant: bool,
+    anonymous: bool,
+    team: Option<String>,
+) -> (TournamentRoomMember, String) {
+    let mut noauth = String::from("not-set");
+
+    let member = match &auth_state.user {
+        Some(user) => {
+            // A rating is close to unique, so it would give anonymous members away.
+            let rating = if anonymous {
+                None
+            } else {
+                app::persistence::ratings::get_rating(&app_state.conn, &user.id)
+                    .await
+                    .inspect_err(|e| warn!("Failed to load rating for {}: {}", user.id, e))
+                    .ok()
+            };
+
+            TournamentRoomMember {
+                rating,
+                team,
+                ..TournamentRoomMember::from_user(user, anonymous, participant)
+            }
+        }
+        None => {
+            let id = match socket
+                .req_parts()
+                .headers
+                .get("x-noauth-unique")
+                .and_then(|value| decode_noauth(value.as_ref()))
+            {
+                Some(id) => id,
+                None => {
+                    let id = Uuid::new_v4().to_string();
+                    noauth = encode_noauth(&id);
+                    id
+                }
+            };
+
+            TournamentRoomMember {
+                id,
+                user: None,
+                participant,
+                rating: None,
+                user_id: None,
+                team,
+            }
+        }
+    };
+
+    (member, noauth)
+}
+
 pub fn register_tournament_namespace(app_state: AppState) {
-    let _ = app_state.socket_io.clone().ns(
+    app_state.socket_io.clone().ns(
         "/",
         async move |HttpExtension(auth_state): HttpExtension<AuthSchema>, socket: SocketRef| {
             let query_string = socket.req_parts().uri.query().unwrap_or_default();
@@ -36,37 +96,31 @@ pub fn register_tournament_namespace(app_state: AppState) {
                 .and_then(|val_str| val_str.parse::<bool>().ok())
                 .unwrap_or(false);
 
+            let waitlist: bool = params_map
+                .get("waitlist")
+                .and_then(|val_str| val_str.parse::<bool>().ok())
+                .unwrap_or(false);
+
             let anonymous: bool = params_map
                 .get("anonymous")
                 .and_then(|val_str| val_str.parse::<bool>().ok())
                 .unwrap_or(false);
 
-            let mut noauth = String::from("not-set");
-
-            let tournament_room_member = match &auth_state.user {
-                Some(user) => TournamentRoomMember::from_user(user, anonymous, !spectator),
-                None => match socket
-                    .req_parts()
-                    .headers
-                    .get("x-noauth-unique")
-                    .and_then(|value| decode_noauth(value.as_ref()))
-                {
-                    Some(id) => TournamentRoomMember {
-                        id,
-                        user: None,
-                        participant: !spectator,
-                    },
-                    None => {
-                        let id = Uuid::new_v4().to_string();
-                        noauth = encode_noauth(&id);
-                        TournamentRoomMember {
-                            id,
-                            user: None,
-                            participant: !spectator,
-                        }
-                    }
-                },
-            };
+            // Names outside the allowed length count as no team at all.
+            let team = params_map
+                .get("team")
+                .map(|val_str| val_str.trim().to_owned())
+                .filter(|team| !team.is_empty() && team.chars().count() <= MAX_TEAM_NAME_LENGTH);
+
+            let (tournament_room_member, noauth) = room_member(
+                &app_state,
+                &auth_state,
+                &socket,
+                !spectator,
+                anonymous,
+                team,
+            )
+            .await;
 
             socket.extensions.insert(Arc::new(tournament_room_member));
 
@@ -90,6 +144,70 @@ pub fn register_tournament_namespace(app_state: AppState) {
                 tournament_id, member.id
             );
 
+            match app::persistence::tournament_invites::can_join_tournament(
+                &app_state.conn,
+                &tournament_id,
+                auth_state.user.as_ref().map(|user| user.id.as_str()),
+            )
+            .await
+            {
+                Ok(true) => {}
+                Ok(false) => {
+                    warn!(
+                        "Member {} is not invited to tournament '{}'",
+                        member.id, tournament_id
+                    );
+                    socket
+                        .emit(
+                            "join:failure",
+                            &WsFailurePayload::new(1006, "You are not invited to this tournament"),
+                        )
+                        .ok();
+                    let _ = socket.disconnect();
+                    return;
+                }
+                Err(e) => {
+                    error!(
+                        "Error checking invites for tournament '{}': {}",
+                        tournament_id, e
+                    );
+                    let _ = socket.disconnect();
+                    return;
+                }
+            }
+
+            match app::persistence::tournament_bans::is_banned(
+                &app_state.conn,
+                &tournament_id,
+                &member.id,
+            )
+            .await
+            {
+                Ok(false) => {}
+                Ok(true) => {
+                    warn!(
+                        "Member {} is banned from tournament '{}'",
+                        member.id, tournament_id
+                    );
+                    socket
+                        .emit(
+                            "join:failure",
+                            &WsFailurePayload::new(1009, "You are banned from this tournament"),
+                        )
+                        .ok();
+                    let _ = socket.disconnect();
+                    return;
+                }
+                Err(e) => {
+                    error!(
+                        "Error checking bans for tournament '{}': {}",
+                        tournament_id, e
+                    );
+                    let _ = socket.disconnect();
+                    return;
+                }
+            }
+
             let tournament_registry = app_state.tournament_registry.clone();
 
             let manager = match tournament_registry.get(&tournament_id) {
@@ -136,10 +254,38 @@ pub fn register_tournament_namespace(app_state: AppState) {
                 }
             };
 
-            if let Err(e) = manager.connect(socket.clone(), spectator, noauth).await {
+            if let Err(e) = manager
+                .connect(socket.clone(), spectator, waitlist, noauth)
+                .await
+            {
                 warn!("Error handling member connection for {}: {}", member.id, e);
                 let _ = socket.disconnect();
             }
         },
     );
 }
+
+pub fn register_practice_namespace(app_state: AppState) {
+    app_state.socket_io.clone().ns(
+        "/practice",
+        async move |HttpExtension(auth_state): HttpExtension<AuthSchema>, socket: SocketRef| {
+            let query_string = socket.req_parts().uri.query().unwrap_or_default();
+            let params_map =
+                url::form_urlencoded::parse(query_string.as_bytes()).collect::<HashMap<_, _>>();
+
+            let anonymous: bool = params_map
+                .get("anonymous")
+                .and_then(|val_str| val_str.parse::<bool>().ok())
+                .unwrap_or(false);
+
+            let (member, noauth) =
+                room_member(&app_state, &auth_state, &socket, true, anonymous, None).await;
+
+            info!("Socket.IO connected for practice: Member: {:?}", member.id);
+
+            socket.extensions.insert(Arc::new(member));
+
+            start_practice(socket, app_state.clone(), noauth).await;
+        },
+    );
+}
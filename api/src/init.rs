@@ -4,12 +4,13 @@ use sea_orm::{ConnectOptions, Database, DatabaseConnection};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
-use crate::action::register_tournament_namespace;
+use crate::action::{register_practice_namespace, register_tournament_namespace};
 use crate::middleware::extension::extension;
 use crate::openapi::ApiDoc;
 use crate::routers::create_router;
-use app::cache::{TournamentRegistry, TypingSessionRegistry};
+use app::cache::{LeaderboardCache, TournamentRegistry, TypingSessionRegistry};
 use app::config::Config;
+use app::persistence::leaderboards::LEADERBOARD_CACHE_TTL;
 use app::state::AppState;
 use socketioxide::SocketIo;
 use tower_http::{cors::CorsLayer, trace::TraceLayer};
@@ -40,6 +41,7 @@ pub fn setup_router(config: Config, conn: DatabaseConnection) -> Router {
 
     let tournament_registry = TournamentRegistry::new();
     let typing_session_registry = TypingSessionRegistry::new();
+    let leaderboard_cache = LeaderboardCache::new(LEADERBOARD_CACHE_TTL);
     let (socket_layer, socket_io) = SocketIo::new_layer();
 
     let app_state = AppState {
@@ -47,13 +49,15 @@ pub fn setup_router(config: Config, conn: DatabaseConnection) -> Router {
         config,
         tournament_registry,
         typing_session_registry,
+        leaderboard_cache,
         socket_io,
     };
 
     {
         let app_state = app_state.clone();
 
-        register_tournament_namespace(app_state);
+        register_tournament_namespace(app_state.clone());
+        register_practice_namespace(app_state);
     }
 
     create_router(app_state.clone())
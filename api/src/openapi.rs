@@ -19,9 +19,38 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
         crate::routers::user::users_post,
         crate::routers::user::users_id_get,
         crate::routers::user::current_user_update,
+        crate::routers::user::users_id_history_get,
+        crate::routers::user::current_user_history_get,
+        crate::routers::user::users_id_stats_get,
+        crate::routers::user::users_id_rating_get,
+        crate::routers::user::users_id_achievements_get,
+        crate::routers::user::current_user_weak_keys_get,
         crate::routers::tournament::tournaments_post,
         crate::routers::tournament::tournaments_get,
         crate::routers::tournament::tournaments_id_get,
+        crate::routers::tournament::tournaments_id_patch,
+        crate::routers::tournament::tournaments_id_delete,
+        crate::routers::tournament::tournaments_id_results_get,
+        crate::routers::tournament::tournaments_id_replay_get,
+        crate::routers::tournament::tournaments_id_invites_get,
+        crate::routers::tournament::tournaments_id_invites_post,
+        crate::routers::tournament::tournaments_id_invites_user_id_delete,
+        crate::routers::tournament::tournaments_id_invite_code_post,
+        crate::routers::tournament::tournaments_invites_code_post,
+        crate::routers::tournament::tournaments_id_kick_post,
+        crate::routers::tournament::tournaments_id_bans_get,
+        crate::routers::tournament::tournaments_id_bans_member_id_delete,
+        crate::routers::series::series_post,
+        crate::routers::series::series_id_get,
+        crate::routers::series::series_id_delete,
+        crate::routers::series::series_id_results_get,
+        crate::routers::leaderboard::leaderboards_get,
+        crate::routers::passage::passages_get,
+        crate::routers::passage::passages_post,
+        crate::routers::passage::passages_id_get,
+        crate::routers::passage::passages_id_patch,
+        crate::routers::passage::passages_id_approve_post,
+        crate::routers::passage::passages_id_retire_post,
     ),
     components(
         schemas(
@@ -29,7 +58,21 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
             crate::ApiResponse<models::schemas::user::UserSchema>,
             crate::ApiResponse<models::schemas::user::AuthSchema>,
             crate::ApiResponse<models::schemas::tournament::TournamentSchema>,
+            crate::ApiResponse<models::schemas::tournament::TournamentResultsSchema>,
+            crate::ApiResponse<models::schemas::replay::RaceReplaySchema>,
+            crate::ApiResponse<models::schemas::invite::TournamentInvitesSchema>,
+            crate::ApiResponse<models::schemas::ban::TournamentBansSchema>,
+            crate::ApiResponse<models::schemas::series::SeriesSchema>,
+            crate::ApiResponse<models::schemas::series::SeriesResultsSchema>,
             crate::ApiResponse<models::schemas::pagination::PaginatedData<models::schemas::tournament::Tournament>>,
+            crate::ApiResponse<models::schemas::pagination::PaginatedData<models::schemas::typing_history::TypingHistorySchema>>,
+            crate::ApiResponse<models::schemas::stats::UserStatsSchema>,
+            crate::ApiResponse<models::schemas::key_stats::WeakKeysSchema>,
+            crate::ApiResponse<models::schemas::rating::UserRatingSchema>,
+            crate::ApiResponse<Vec<models::schemas::achievement::AchievementSchema>>,
+            crate::ApiResponse<models::schemas::leaderboard::LeaderboardSchema>,
+            crate::ApiResponse<models::schemas::passage::PassageSchema>,
+            crate::ApiResponse<models::schemas::pagination::PaginatedData<models::schemas::passage::PassageSchema>>,
             models::schemas::user::UserSchema,
             models::schemas::user::LoginSchema,
             models::schemas::user::TokensSchema,
@@ -41,10 +84,33 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
             models::schemas::tournament::TournamentSession,
             models::schemas::tournament::Tournament,
             models::schemas::tournament::TournamentLiveData,
+            models::schemas::tournament::TournamentResultsSchema,
+            models::schemas::tournament::StandingSchema,
+            models::schemas::tournament::TeamStandingSchema,
+            models::schemas::tournament::RoundTextSchema,
+            models::schemas::replay::RaceReplaySchema,
+            models::schemas::replay::ReplayTrackSchema,
+            models::schemas::replay::ReplayEventSchema,
+            models::schemas::ban::TournamentBansSchema,
+            models::schemas::ban::BannedMemberSchema,
             models::schemas::typing::TextOptions,
             models::schemas::typing::TournamentStatus,
             models::schemas::typing::TypingSessionSchema,
+            models::schemas::typing_history::TypingHistorySchema,
+            models::schemas::stats::UserStatsSchema,
+            models::schemas::stats::SpeedPercentiles,
+            models::schemas::stats::SpeedTrend,
+            models::schemas::key_stats::WeakKeysSchema,
+            models::schemas::key_stats::KeyErrorSchema,
+            models::schemas::rating::UserRatingSchema,
+            models::schemas::rating::RatingHistorySchema,
+            models::schemas::achievement::AchievementSchema,
+            models::schemas::leaderboard::LeaderboardSchema,
+            models::schemas::leaderboard::LeaderboardEntrySchema,
+            models::schemas::passage::PassageSchema,
             models::schemas::pagination::PaginatedData<models::schemas::tournament::Tournament>,
+            models::schemas::pagination::PaginatedData<models::schemas::typing_history::TypingHistorySchema>,
+            models::schemas::pagination::PaginatedData<models::schemas::passage::PassageSchema>,
             models::schemas::pagination::ListSchema<models::schemas::tournament::TournamentSchema>,
             models::params::user::CreateUserParams,
             models::params::user::LoginUserParams,
@@ -54,7 +120,24 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
             models::params::user::AuthCodeParams,
             models::params::tournament::CreateTournamentParams,
             models::params::tournament::UpdateTournamentParams,
+            models::params::tournament::InviteUsersParams,
+            models::params::tournament::KickMemberParams,
+            models::params::series::CreateSeriesParams,
+            models::params::passage::CreatePassageParams,
+            models::params::passage::UpdatePassageParams,
+            models::schemas::series::RecurrenceRule,
+            models::schemas::series::RecurrenceDay,
             models::domains::sea_orm_active_enums::TournamentPrivacy,
+            models::domains::sea_orm_active_enums::RaceOutcome,
+            models::domains::sea_orm_active_enums::TeamScoring,
+            models::domains::sea_orm_active_enums::RaceMode,
+            models::domains::sea_orm_active_enums::PassageStatus,
+            models::domains::sea_orm_active_enums::TextDifficulty,
+            models::domains::sea_orm_active_enums::TextLanguage,
+            models::queries::typing_history::SortOrder,
+            models::queries::typing_history::TypingHistorySortBy,
+            models::queries::leaderboard::LeaderboardPeriod,
+            models::queries::leaderboard::LeaderboardMetric,
         )
     ),
     modifiers(&SecurityAddon),
@@ -62,6 +145,9 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
         (name = "auth", description = "Authentication endpoints"),
         (name = "users", description = "User management endpoints"),
         (name = "tournaments", description = "Tournament management endpoints"),
+        (name = "series", description = "Recurring tournament series"),
+        (name = "leaderboards", description = "Ranked boards built from race results"),
+        (name = "passages", description = "Real-world passages raced as meaningful texts"),
     )
 )]
 pub struct ApiDoc;
@@ -0,0 +1,43 @@
+use axum::{
+    Router,
+    extract::{Query, State},
+    response::IntoResponse,
+    routing::get,
+};
+
+use app::persistence::leaderboards::get_leaderboard;
+use app::state::AppState;
+use models::queries::leaderboard::LeaderboardQuery;
+use models::schemas::leaderboard::LeaderboardSchema;
+
+use crate::extractor::Json;
+use crate::{ApiResponse, error::ApiError};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/leaderboards",
+    tag = "leaderboards",
+    params(
+        LeaderboardQuery
+    ),
+    responses(
+        (status = 200, description = "Leaderboard retrieved successfully", body = ApiResponse<LeaderboardSchema>),
+    )
+)]
+#[axum::debug_handler]
+pub async fn leaderboards_get(
+    state: State<AppState>,
+    Query(query): Query<LeaderboardQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let result = get_leaderboard(&state, query)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Leaderboard retrieved successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+pub fn create_leaderboard_router() -> Router<AppState> {
+    Router::new().route("/", get(leaderboards_get))
+}
@@ -2,12 +2,18 @@ use auth::create_auth_router;
 use axum::Router;
 
 pub mod auth;
+pub mod leaderboard;
+pub mod passage;
 pub mod root;
+pub mod series;
 pub mod tournament;
 pub mod user;
 
 use app::state::AppState;
+use leaderboard::create_leaderboard_router;
+use passage::create_passage_router;
 use root::create_root_router;
+use series::create_series_router;
 use tournament::create_tournament_router;
 use user::create_user_router;
 
@@ -15,7 +21,10 @@ pub fn create_router(state: AppState) -> Router {
     let routes = Router::new()
         .nest("/auth", create_auth_router())
         .nest("/users", create_user_router())
-        .nest("/tournaments", create_tournament_router());
+        .nest("/tournaments", create_tournament_router())
+        .nest("/series", create_series_router())
+        .nest("/leaderboards", create_leaderboard_router())
+        .nest("/passages", create_passage_router());
 
     Router::new()
         .merge(create_root_router())
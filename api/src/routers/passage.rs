@@ -0,0 +1,277 @@
+use axum::{
+    Extension, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use app::error::CustomError;
+use app::persistence::passages::{
+    count_words, create_passage, get_passage, get_passages, set_passage_status, update_passage,
+};
+use app::persistence::users::get_user;
+use app::state::AppState;
+use models::domains::sea_orm_active_enums::{PassageStatus, TextLanguage, UserRole};
+use models::params::passage::{CreatePassageParams, UpdatePassageParams};
+use models::queries::passage::PassageQuery;
+use models::schemas::pagination::PaginatedData;
+use models::schemas::passage::PassageSchema;
+use models::schemas::typing::{MAX_WORD_COUNT, MIN_WORD_COUNT};
+use models::schemas::user::AuthSchema;
+
+use crate::extractor::{Json, Valid};
+use crate::{ApiResponse, error::ApiError};
+
+/// The passage corpus is managed by users with the admin role. The role is read
+/// from the database rather than the token, so revoking it takes effect at once.
+async fn require_admin(state: &AppState, auth_state: &AuthSchema) -> Result<(), ApiError> {
+    let user = auth_state
+        .user
+        .as_ref()
+        .ok_or_else(|| CustomError::new(StatusCode::UNAUTHORIZED, "User not logged in".into()))?;
+
+    let role = get_user(state, &user.id).await?.map(|user| user.role);
+    if role != Some(UserRole::Admin) {
+        return Err(
+            CustomError::new(StatusCode::FORBIDDEN, "Only admins can do this".into()).into(),
+        );
+    }
+
+    Ok(())
+}
+
+/// A passage has to fit in a race text.
+fn check_word_count(text: &str, language: TextLanguage) -> Result<(), ApiError> {
+    let words = count_words(text, language) as u32;
+    if !(MIN_WORD_COUNT..=MAX_WORD_COUNT).contains(&words) {
+        return Err(CustomError::new(
+            StatusCode::BAD_REQUEST,
+            format!("Passages must have between {MIN_WORD_COUNT} and {MAX_WORD_COUNT} words"),
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/passages",
+    tag = "passages",
+    params(
+        PassageQuery
+    ),
+    responses(
+        (status = 200, description = "Passages retrieved successfully", body = ApiResponse<PaginatedData<PassageSchema>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn passages_get(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Query(query): Query<PassageQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, &auth_state).await?;
+
+    let result = get_passages(&state.conn, query)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Passages retrieved successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/passages",
+    tag = "passages",
+    request_body = CreatePassageParams,
+    responses(
+        (status = 200, description = "Passage uploaded successfully", body = ApiResponse<PassageSchema>),
+        (status = 400, description = "Too few or too many words"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn passages_post(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Valid(Json(params)): Valid<Json<CreatePassageParams>>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, &auth_state).await?;
+    check_word_count(&params.text, params.language.unwrap_or_default())?;
+
+    let result = create_passage(&state.conn, params)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Passage uploaded successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/passages/{id}",
+    tag = "passages",
+    params(
+        ("id" = i32, Path, description = "Passage ID")
+    ),
+    responses(
+        (status = 200, description = "Passage retrieved successfully", body = ApiResponse<PassageSchema>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin"),
+        (status = 404, description = "Passage not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn passages_id_get(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, &auth_state).await?;
+
+    let result = get_passage(&state.conn, id)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "Passage not found".into()))?;
+
+    let response = ApiResponse::success("Passage retrieved successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/v1/passages/{id}",
+    tag = "passages",
+    params(
+        ("id" = i32, Path, description = "Passage ID")
+    ),
+    request_body = UpdatePassageParams,
+    responses(
+        (status = 200, description = "Passage updated successfully", body = ApiResponse<PassageSchema>),
+        (status = 400, description = "Too few or too many words"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin"),
+        (status = 404, description = "Passage not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn passages_id_patch(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<i32>,
+    Valid(Json(params)): Valid<Json<UpdatePassageParams>>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, &auth_state).await?;
+    if params.text.is_some() || params.language.is_some() {
+        let passage = get_passage(&state.conn, id)
+            .await
+            .map_err(ApiError::from)?
+            .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "Passage not found".into()))?;
+        check_word_count(
+            params.text.as_deref().unwrap_or(&passage.text),
+            params.language.unwrap_or(passage.language),
+        )?;
+    }
+
+    let result = update_passage(&state.conn, id, params)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "Passage not found".into()))?;
+
+    let response = ApiResponse::success("Passage updated successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/passages/{id}/approve",
+    tag = "passages",
+    params(
+        ("id" = i32, Path, description = "Passage ID")
+    ),
+    responses(
+        (status = 200, description = "Passage approved successfully", body = ApiResponse<PassageSchema>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin"),
+        (status = 404, description = "Passage not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn passages_id_approve_post(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, &auth_state).await?;
+
+    let result = set_passage_status(&state.conn, id, PassageStatus::Approved)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "Passage not found".into()))?;
+
+    let response = ApiResponse::success("Passage approved successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/passages/{id}/retire",
+    tag = "passages",
+    params(
+        ("id" = i32, Path, description = "Passage ID")
+    ),
+    responses(
+        (status = 200, description = "Passage retired successfully", body = ApiResponse<PassageSchema>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an admin"),
+        (status = 404, description = "Passage not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn passages_id_retire_post(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, &auth_state).await?;
+
+    let result = set_passage_status(&state.conn, id, PassageStatus::Retired)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "Passage not found".into()))?;
+
+    let response = ApiResponse::success("Passage retired successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+pub fn create_passage_router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(passages_get).post(passages_post))
+        .route("/{id}", get(passages_id_get).patch(passages_id_patch))
+        .route("/{id}/approve", post(passages_id_approve_post))
+        .route("/{id}/retire", post(passages_id_retire_post))
+}
@@ -0,0 +1,167 @@
+use axum::{
+    Extension, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use app::error::CustomError;
+use app::persistence::series::{create_series, deactivate_series, get_series, get_series_results};
+use app::state::AppState;
+use models::params::series::CreateSeriesParams;
+use models::schemas::series::{SeriesResultsSchema, SeriesSchema};
+use models::schemas::user::AuthSchema;
+
+use crate::extractor::{Json, Valid};
+use crate::{ApiResponse, error::ApiError};
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/series",
+    tag = "series",
+    request_body = CreateSeriesParams,
+    responses(
+        (status = 200, description = "Series created successfully", body = ApiResponse<SeriesSchema>),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn series_post(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Valid(Json(params)): Valid<Json<CreateSeriesParams>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = &auth_state
+        .user
+        .as_ref()
+        .ok_or_else(|| CustomError::new(StatusCode::UNAUTHORIZED, "User not logged in".into()))?
+        .id;
+
+    let series = create_series(&state.conn, params, user_id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let result = get_series(&state.conn, &series.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Series created successfully", result);
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/series/{id}",
+    tag = "series",
+    params(
+        ("id" = String, Path, description = "Series ID")
+    ),
+    responses(
+        (status = 200, description = "Series retrieved successfully", body = ApiResponse<SeriesSchema>),
+        (status = 404, description = "Series not found"),
+    )
+)]
+pub async fn series_id_get(
+    state: State<AppState>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let result = get_series(&state.conn, &id)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "Series not found".into()))?;
+
+    let response = ApiResponse::success("Series retrieved successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/series/{id}",
+    tag = "series",
+    params(
+        ("id" = String, Path, description = "Series ID")
+    ),
+    responses(
+        (status = 200, description = "Series stopped successfully", body = ApiResponse<SeriesSchema>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the series creator"),
+        (status = 404, description = "Series not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn series_id_delete(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = &auth_state
+        .user
+        .as_ref()
+        .ok_or_else(|| CustomError::new(StatusCode::UNAUTHORIZED, "User not logged in".into()))?
+        .id;
+
+    let series = get_series(&state.conn, &id)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "Series not found".into()))?;
+
+    if &series.created_by != user_id {
+        return Err(CustomError::new(
+            StatusCode::FORBIDDEN,
+            "Only the series creator can do this".into(),
+        )
+        .into());
+    }
+
+    deactivate_series(&state.conn, &series.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let result = get_series(&state.conn, &series.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Series stopped successfully", result);
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/series/{id}/results",
+    tag = "series",
+    params(
+        ("id" = String, Path, description = "Series ID")
+    ),
+    responses(
+        (status = 200, description = "Series results retrieved successfully", body = ApiResponse<SeriesResultsSchema>),
+        (status = 404, description = "Series not found"),
+    )
+)]
+pub async fn series_id_results_get(
+    state: State<AppState>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let result = get_series_results(&state.conn, &id)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "Series not found".into()))?;
+
+    let response = ApiResponse::success("Series results retrieved successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+pub fn create_series_router() -> Router<AppState> {
+    Router::new()
+        .route("/", post(series_post))
+        .route("/{id}", get(series_id_get).delete(series_id_delete))
+        .route("/{id}/results", get(series_id_results_get))
+}
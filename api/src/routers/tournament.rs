@@ -2,16 +2,38 @@ use anyhow::anyhow;
 use axum::{
     Extension, Router,
     extract::{Path, Query, Request, State},
+    http::StatusCode,
     response::IntoResponse,
-    routing::{get, post},
+    routing::{delete, get, patch, post},
 };
+use chrono::Utc;
 
-use app::persistence::tournaments::{create_tournament, get_tournament, search_tournaments};
+use app::core::LeaveReason;
+use app::error::CustomError;
+use app::persistence::race_replays::get_race_replay;
+use app::persistence::tournament_bans::{ban_member, get_tournament_bans, lift_ban};
+use app::persistence::tournament_invites::{
+    can_join_tournament, get_tournament_invites, invite_users, redeem_invite_code, revoke_invite,
+    rotate_invite_code,
+};
+use app::persistence::tournament_results::{get_team_standings, get_tournament_standings};
+use app::persistence::tournament_texts::regenerate_tournament_texts;
+use app::persistence::tournaments::{
+    create_tournament, delete_tournament, get_tournament, search_tournaments, update_tournament,
+};
 use app::state::AppState;
-use models::params::tournament::CreateTournamentParams;
+use models::params::tournament::{
+    CreateTournamentParams, InviteUsersParams, KickMemberParams, UpdateTournamentParams,
+};
+use models::schemas::ban::TournamentBansSchema;
+use models::schemas::invite::TournamentInvitesSchema;
 use models::schemas::pagination::PaginatedData;
-use models::schemas::tournament::{Tournament, TournamentSchema};
-use models::{queries::TournamentPaginationQuery, schemas::user::AuthSchema};
+use models::schemas::replay::RaceReplaySchema;
+use models::schemas::tournament::{Tournament, TournamentResultsSchema, TournamentSchema};
+use models::{
+    queries::TournamentPaginationQuery,
+    schemas::user::{AuthSchema, TournamentRoomMember},
+};
 
 use crate::{ApiResponse, error::ApiError};
 use crate::{
@@ -27,6 +49,9 @@ use crate::{
     responses(
         (status = 200, description = "Tournament created successfully", body = ApiResponse<TournamentSchema>),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Rematched tournament was created by someone else"),
+        (status = 404, description = "Rematched tournament not found"),
+        (status = 409, description = "Rematched tournament has not ended or has no text seed"),
     ),
     security(
         ("bearer_auth" = [])
@@ -37,14 +62,25 @@ pub async fn tournaments_post(
     Extension(auth_state): Extension<AuthSchema>,
     Valid(Json(params)): Valid<Json<CreateTournamentParams>>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let user = match auth_state.user {
+    let user = match &auth_state.user {
         Some(user) => user,
         None => return Err(ApiError::from(anyhow!("Unauthorized"))),
     };
 
     tracing::debug!("Creating tournament with params: {:?}", params);
 
-    let tournament = create_tournament(&state.conn, params, &user)
+    if let Some(id) = params.rematch_of.clone() {
+        let rematched = get_owned_tournament(&state, &auth_state, id).await?;
+        if rematched.ended_at.is_none() || rematched.text_seed.is_none() {
+            return Err(CustomError::new(
+                StatusCode::CONFLICT,
+                "Only an ended tournament with a text seed can be rematched".into(),
+            )
+            .into());
+        }
+    }
+
+    let tournament = create_tournament(&state.conn, params, user)
         .await
         .map_err(ApiError::from)?;
 
@@ -107,14 +143,613 @@ pub async fn tournaments_get(
 #[axum::debug_handler]
 pub async fn tournaments_id_get(
     state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
     Path(id): Path<String>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let result = get_tournament(&state.conn, id)
+    let result = get_visible_tournament(&state, &auth_state, id).await?;
+
+    let response = ApiResponse::success("Tournament retrieved Successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+/// Loads a tournament the caller may see. Invitational tournaments are reported
+/// missing to anyone but their creator and invited users, as in listings.
+async fn get_visible_tournament(
+    state: &AppState,
+    auth_state: &AuthSchema,
+    id: String,
+) -> Result<TournamentSchema, ApiError> {
+    let not_found = || CustomError::new(StatusCode::NOT_FOUND, "Tournament not found".into());
+
+    let tournament = get_tournament(&state.conn, id)
         .await
         .map_err(ApiError::from)?
-        .ok_or_else(|| ApiError::from(anyhow!("Tournament not found")))?;
+        .ok_or_else(not_found)?;
 
-    let response = ApiResponse::success("Tournament retrieved Successfully", Some(result));
+    let user_id = auth_state.user.as_ref().map(|user| user.id.as_str());
+    if !can_join_tournament(&state.conn, &tournament.id, user_id)
+        .await
+        .map_err(ApiError::from)?
+    {
+        return Err(not_found().into());
+    }
+
+    Ok(tournament)
+}
+
+/// Loads a tournament the caller created.
+async fn get_owned_tournament(
+    state: &AppState,
+    auth_state: &AuthSchema,
+    id: String,
+) -> Result<TournamentSchema, ApiError> {
+    let user = auth_state
+        .user
+        .as_ref()
+        .ok_or_else(|| CustomError::new(StatusCode::UNAUTHORIZED, "User not logged in".into()))?;
+
+    let tournament = get_tournament(&state.conn, id)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "Tournament not found".into()))?;
+
+    if tournament.created_by != user.id {
+        return Err(CustomError::new(
+            StatusCode::FORBIDDEN,
+            "Only the tournament creator can do this".into(),
+        )
+        .into());
+    }
+
+    Ok(tournament)
+}
+
+/// Loads a tournament the caller created that has not started yet, so it can
+/// still be edited or cancelled.
+async fn get_editable_tournament(
+    state: &AppState,
+    auth_state: &AuthSchema,
+    id: String,
+) -> Result<TournamentSchema, ApiError> {
+    let tournament = get_owned_tournament(state, auth_state, id).await?;
+
+    let started = match state.tournament_registry.get(&tournament.id) {
+        Some(manager) => manager.has_started().await,
+        None => tournament.started_at.is_some() || tournament.ended_at.is_some(),
+    };
+
+    if started {
+        return Err(CustomError::new(
+            StatusCode::CONFLICT,
+            "Tournament has already started".into(),
+        )
+        .into());
+    }
+
+    Ok(tournament)
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/v1/tournaments/{id}",
+    tag = "tournaments",
+    params(
+        ("id" = String, Path, description = "Tournament ID")
+    ),
+    request_body = UpdateTournamentParams,
+    responses(
+        (status = 200, description = "Tournament updated successfully", body = ApiResponse<TournamentSchema>),
+        (status = 400, description = "Scheduled in the past"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the tournament creator"),
+        (status = 404, description = "Tournament not found"),
+        (status = 409, description = "Tournament has already started"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn tournaments_id_patch(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+    Valid(Json(params)): Valid<Json<UpdateTournamentParams>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tournament = get_editable_tournament(&state, &auth_state, id).await?;
+
+    if params
+        .scheduled_for
+        .is_some_and(|scheduled_for| scheduled_for < Utc::now())
+    {
+        return Err(CustomError::new(
+            StatusCode::BAD_REQUEST,
+            "Tournaments can't be scheduled in the past".into(),
+        )
+        .into());
+    }
+
+    let params = UpdateTournamentParams {
+        id: Some(tournament.id.clone()),
+        ..params
+    };
+    let write = async {
+        update_tournament(&state, params)
+            .await
+            .map(TournamentSchema::from)
+    };
+
+    // A live tournament only takes the update if it hasn't started in the meantime.
+    let tournament = match state.tournament_registry.get(&tournament.id) {
+        Some(manager) => manager.apply_update(write).await.map_err(ApiError::from)?,
+        None => Some(write.await.map_err(ApiError::from)?),
+    }
+    .ok_or_else(|| {
+        CustomError::new(
+            StatusCode::CONFLICT,
+            "Tournament has already started".into(),
+        )
+    })?;
+
+    let response = ApiResponse::success("Tournament updated successfully", Some(tournament));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/tournaments/{id}",
+    tag = "tournaments",
+    params(
+        ("id" = String, Path, description = "Tournament ID")
+    ),
+    responses(
+        (status = 200, description = "Tournament cancelled successfully", body = ApiResponse<TournamentSchema>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the tournament creator"),
+        (status = 404, description = "Tournament not found"),
+        (status = 409, description = "Tournament has already started"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn tournaments_id_delete(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tournament = get_editable_tournament(&state, &auth_state, id).await?;
+
+    if let Some(manager) = state.tournament_registry.get(&tournament.id) {
+        manager
+            .cancel()
+            .await
+            .map_err(|e| CustomError::new(StatusCode::CONFLICT, e.to_string()))?;
+    }
+
+    delete_tournament(&state.conn, &tournament.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Tournament cancelled successfully", Some(tournament));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/tournaments/{id}/results",
+    tag = "tournaments",
+    params(
+        ("id" = String, Path, description = "Tournament ID")
+    ),
+    responses(
+        (status = 200, description = "Tournament results retrieved successfully", body = ApiResponse<TournamentResultsSchema>),
+        (status = 404, description = "Tournament not found"),
+        (status = 409, description = "Tournament has not ended yet"),
+    )
+)]
+pub async fn tournaments_id_results_get(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tournament = get_visible_tournament(&state, &auth_state, id).await?;
+
+    let ended_at = tournament.ended_at.ok_or_else(|| {
+        CustomError::new(StatusCode::CONFLICT, "Tournament has not ended yet".into())
+    })?;
+
+    let standings = get_tournament_standings(&state.conn, &tournament.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let teams = get_team_standings(&state.conn, &tournament.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let texts = regenerate_tournament_texts(&state.conn, &tournament)
+        .await
+        .map_err(ApiError::from)?;
+
+    let result = TournamentResultsSchema {
+        tournament_id: tournament.id,
+        ended_at,
+        text_seed: tournament.text_seed,
+        texts,
+        standings,
+        teams,
+    };
+
+    let response = ApiResponse::success("Tournament results retrieved Successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/tournaments/{id}/replay",
+    tag = "tournaments",
+    params(
+        ("id" = String, Path, description = "Tournament ID")
+    ),
+    responses(
+        (status = 200, description = "Race replay retrieved successfully", body = ApiResponse<RaceReplaySchema>),
+        (status = 404, description = "Tournament not found"),
+        (status = 409, description = "Tournament has not ended yet"),
+    )
+)]
+pub async fn tournaments_id_replay_get(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tournament = get_visible_tournament(&state, &auth_state, id).await?;
+
+    if tournament.ended_at.is_none() {
+        return Err(
+            CustomError::new(StatusCode::CONFLICT, "Tournament has not ended yet".into()).into(),
+        );
+    }
+
+    let result = get_race_replay(&state.conn, &tournament.id)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "Tournament not found".into()))?;
+
+    let response = ApiResponse::success("Race replay retrieved Successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/tournaments/{id}/invites",
+    tag = "tournaments",
+    params(
+        ("id" = String, Path, description = "Tournament ID")
+    ),
+    responses(
+        (status = 200, description = "Tournament invites retrieved successfully", body = ApiResponse<TournamentInvitesSchema>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the tournament creator"),
+        (status = 404, description = "Tournament not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn tournaments_id_invites_get(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tournament = get_owned_tournament(&state, &auth_state, id).await?;
+
+    let result = get_tournament_invites(&state.conn, &tournament.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Tournament invites retrieved successfully", result);
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/tournaments/{id}/invites",
+    tag = "tournaments",
+    params(
+        ("id" = String, Path, description = "Tournament ID")
+    ),
+    request_body = InviteUsersParams,
+    responses(
+        (status = 200, description = "Users invited successfully", body = ApiResponse<TournamentInvitesSchema>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the tournament creator"),
+        (status = 404, description = "Tournament not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn tournaments_id_invites_post(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+    Valid(Json(params)): Valid<Json<InviteUsersParams>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tournament = get_owned_tournament(&state, &auth_state, id).await?;
+
+    invite_users(&state.conn, &tournament.id, params.user_ids, Utc::now())
+        .await
+        .map_err(ApiError::from)?;
+
+    let result = get_tournament_invites(&state.conn, &tournament.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Users invited successfully", result);
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/tournaments/{id}/invites/{user_id}",
+    tag = "tournaments",
+    params(
+        ("id" = String, Path, description = "Tournament ID"),
+        ("user_id" = String, Path, description = "ID of the invited user")
+    ),
+    responses(
+        (status = 200, description = "Invite revoked successfully", body = ApiResponse<TournamentInvitesSchema>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the tournament creator"),
+        (status = 404, description = "Tournament or invite not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn tournaments_id_invites_user_id_delete(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path((id, user_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tournament = get_owned_tournament(&state, &auth_state, id).await?;
+
+    let revoked = revoke_invite(&state.conn, &tournament.id, &user_id)
+        .await
+        .map_err(ApiError::from)?;
+
+    if !revoked {
+        return Err(CustomError::new(StatusCode::NOT_FOUND, "Invite not found".into()).into());
+    }
+
+    let result = get_tournament_invites(&state.conn, &tournament.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Invite revoked successfully", result);
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/tournaments/{id}/invite-code",
+    tag = "tournaments",
+    params(
+        ("id" = String, Path, description = "Tournament ID")
+    ),
+    responses(
+        (status = 200, description = "Invite code regenerated successfully", body = ApiResponse<TournamentInvitesSchema>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the tournament creator"),
+        (status = 404, description = "Tournament not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn tournaments_id_invite_code_post(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tournament = get_owned_tournament(&state, &auth_state, id).await?;
+
+    rotate_invite_code(&state.conn, &tournament.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let result = get_tournament_invites(&state.conn, &tournament.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Invite code regenerated successfully", result);
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/tournaments/invites/{code}",
+    tag = "tournaments",
+    params(
+        ("code" = String, Path, description = "Shareable invite code")
+    ),
+    responses(
+        (status = 200, description = "Invite code redeemed successfully", body = ApiResponse<TournamentSchema>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Invite code not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn tournaments_invites_code_post(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(code): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = &auth_state
+        .user
+        .as_ref()
+        .ok_or_else(|| CustomError::new(StatusCode::UNAUTHORIZED, "User not logged in".into()))?
+        .id;
+
+    let tournament = redeem_invite_code(&state.conn, &code, user_id, Utc::now())
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "Invite code not found".into()))?;
+
+    let response = ApiResponse::success(
+        "Invite code redeemed successfully",
+        Some(TournamentSchema::from(tournament)),
+    );
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/tournaments/{id}/kick",
+    tag = "tournaments",
+    params(
+        ("id" = String, Path, description = "Tournament ID")
+    ),
+    request_body = KickMemberParams,
+    responses(
+        (status = 200, description = "Member removed successfully", body = ApiResponse<TournamentBansSchema>),
+        (status = 400, description = "The creator cannot remove themselves"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the tournament creator"),
+        (status = 404, description = "Tournament or member not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn tournaments_id_kick_post(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+    Valid(Json(params)): Valid<Json<KickMemberParams>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tournament = get_owned_tournament(&state, &auth_state, id).await?;
+
+    if params.member_id == TournamentRoomMember::get_id(&tournament.created_by) {
+        return Err(CustomError::new(
+            StatusCode::BAD_REQUEST,
+            "The tournament creator cannot be removed".into(),
+        )
+        .into());
+    }
+
+    let ban = params.ban.unwrap_or(false);
+    if ban {
+        ban_member(&state.conn, &tournament.id, &params.member_id, Utc::now())
+            .await
+            .map_err(ApiError::from)?;
+    }
+
+    let reason = if ban {
+        LeaveReason::Banned
+    } else {
+        LeaveReason::Kicked
+    };
+    let removed = match state.tournament_registry.get(&tournament.id) {
+        Some(manager) => manager.remove_member(&params.member_id, reason).await,
+        None => false,
+    };
+
+    if !removed && !ban {
+        return Err(CustomError::new(StatusCode::NOT_FOUND, "Member not found".into()).into());
+    }
+
+    let result = get_tournament_bans(&state.conn, &tournament.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Member removed successfully", result);
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/tournaments/{id}/bans",
+    tag = "tournaments",
+    params(
+        ("id" = String, Path, description = "Tournament ID")
+    ),
+    responses(
+        (status = 200, description = "Tournament bans retrieved successfully", body = ApiResponse<TournamentBansSchema>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the tournament creator"),
+        (status = 404, description = "Tournament not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn tournaments_id_bans_get(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tournament = get_owned_tournament(&state, &auth_state, id).await?;
+
+    let result = get_tournament_bans(&state.conn, &tournament.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Tournament bans retrieved successfully", result);
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/tournaments/{id}/bans/{member_id}",
+    tag = "tournaments",
+    params(
+        ("id" = String, Path, description = "Tournament ID"),
+        ("member_id" = String, Path, description = "ID of the banned member")
+    ),
+    responses(
+        (status = 200, description = "Ban lifted successfully", body = ApiResponse<TournamentBansSchema>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the tournament creator"),
+        (status = 404, description = "Tournament or ban not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn tournaments_id_bans_member_id_delete(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path((id, member_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tournament = get_owned_tournament(&state, &auth_state, id).await?;
+
+    let lifted = lift_ban(&state.conn, &tournament.id, &member_id)
+        .await
+        .map_err(ApiError::from)?;
+
+    if !lifted {
+        return Err(CustomError::new(StatusCode::NOT_FOUND, "Ban not found".into()).into());
+    }
+
+    let result = get_tournament_bans(&state.conn, &tournament.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Ban lifted successfully", result);
 
     Ok(Json(response))
 }
@@ -124,4 +759,24 @@ pub fn create_tournament_router() -> Router<AppState> {
         .route("/", get(tournaments_get))
         .route("/", post(tournaments_post))
         .route("/{id}", get(tournaments_id_get))
+        .route(
+            "/{id}",
+            patch(tournaments_id_patch).delete(tournaments_id_delete),
+        )
+        .route("/{id}/results", get(tournaments_id_results_get))
+        .route("/{id}/replay", get(tournaments_id_replay_get))
+        .route("/{id}/invites", get(tournaments_id_invites_get))
+        .route("/{id}/invites", post(tournaments_id_invites_post))
+        .route(
+            "/{id}/invites/{user_id}",
+            delete(tournaments_id_invites_user_id_delete),
+        )
+        .route("/{id}/invite-code", post(tournaments_id_invite_code_post))
+        .route("/invites/{code}", post(tournaments_invites_code_post))
+        .route("/{id}/kick", post(tournaments_id_kick_post))
+        .route("/{id}/bans", get(tournaments_id_bans_get))
+        .route(
+            "/{id}/bans/{member_id}",
+            delete(tournaments_id_bans_member_id_delete),
+        )
 }
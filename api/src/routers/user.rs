@@ -1,22 +1,37 @@
 use anyhow::anyhow;
 use axum::{
     Extension, Router,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     routing::{get, patch, post},
 };
 use sea_orm::TryIntoModel;
 
+use app::persistence::achievements::get_user_achievements;
+use app::persistence::key_stats::get_weak_keys;
+use app::persistence::ratings::get_user_rating;
+use app::persistence::stats::{DEFAULT_TREND_RACES, get_user_stats};
+use app::persistence::typing_history::get_user_history;
 use app::persistence::users::{create_user, get_user};
 use app::state::AppState;
 use app::{error::CustomError, persistence::users::update_user};
+use models::queries::{
+    key_stats::WeakKeysQuery, stats::UserStatsQuery, typing_history::TypingHistoryQuery,
+};
+use models::schemas::achievement::AchievementSchema;
+use models::schemas::key_stats::WeakKeysSchema;
+use models::schemas::pagination::PaginatedData;
+use models::schemas::rating::UserRatingSchema;
+use models::schemas::stats::UserStatsSchema;
+use models::schemas::typing_history::TypingHistorySchema;
 use models::schemas::user::UserSchema;
 use models::{
     params::user::{CreateUserParams, UpdateUserParams},
     schemas::user::AuthSchema,
 };
 
+use crate::ApiResponse;
 use crate::error::ApiError;
 use crate::extractor::{Json, Valid};
 
@@ -98,10 +113,229 @@ pub async fn current_user_update(
     Ok((StatusCode::CREATED, Json(UserSchema::from(updated_user))))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{id}/history",
+    tag = "users",
+    params(
+        ("id" = String, Path, description = "User ID"),
+        TypingHistoryQuery
+    ),
+    responses(
+        (status = 200, description = "Typing history retrieved successfully", body = ApiResponse<PaginatedData<TypingHistorySchema>>),
+        (status = 404, description = "User not found"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn users_id_history_get(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+    Query(query): Query<TypingHistoryQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    get_user(&state, &id)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "user not found".into()))?;
+
+    let is_self = auth_state.user.as_ref().is_some_and(|user| user.id == id);
+
+    let result = get_user_history(&state.conn, &id, query, is_self)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Typing history retrieved successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/history",
+    tag = "users",
+    params(
+        TypingHistoryQuery
+    ),
+    responses(
+        (status = 200, description = "Typing history retrieved successfully", body = ApiResponse<PaginatedData<TypingHistorySchema>>),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+#[axum::debug_handler]
+pub async fn current_user_history_get(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Query(query): Query<TypingHistoryQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = &auth_state
+        .user
+        .as_ref()
+        .ok_or_else(|| CustomError::new(StatusCode::UNAUTHORIZED, "User not logged in".into()))?
+        .id;
+
+    let result = get_user_history(&state.conn, user_id, query, true)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Typing history retrieved successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{id}/stats",
+    tag = "users",
+    params(
+        ("id" = String, Path, description = "User ID"),
+        UserStatsQuery
+    ),
+    responses(
+        (status = 200, description = "Statistics retrieved successfully", body = ApiResponse<UserStatsSchema>),
+        (status = 404, description = "User not found"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn users_id_stats_get(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+    Query(query): Query<UserStatsQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    get_user(&state, &id)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "user not found".into()))?;
+
+    let is_self = auth_state.user.as_ref().is_some_and(|user| user.id == id);
+    let recent = query
+        .recent
+        .map(|recent| recent as usize)
+        .unwrap_or(DEFAULT_TREND_RACES);
+
+    let result = get_user_stats(&state.conn, &id, recent, is_self)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Statistics retrieved successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{id}/rating",
+    tag = "users",
+    params(
+        ("id" = String, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Rating retrieved successfully", body = ApiResponse<UserRatingSchema>),
+        (status = 404, description = "User not found"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn users_id_rating_get(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    get_user(&state, &id)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "user not found".into()))?;
+
+    let is_self = auth_state.user.as_ref().is_some_and(|user| user.id == id);
+
+    let result = get_user_rating(&state.conn, &id, is_self)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Rating retrieved successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{id}/achievements",
+    tag = "users",
+    params(
+        ("id" = String, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Achievements retrieved successfully", body = ApiResponse<Vec<AchievementSchema>>),
+        (status = 404, description = "User not found"),
+    )
+)]
+#[axum::debug_handler]
+pub async fn users_id_achievements_get(
+    state: State<AppState>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    get_user(&state, &id)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| CustomError::new(StatusCode::NOT_FOUND, "user not found".into()))?;
+
+    let result = get_user_achievements(&state.conn, &id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Achievements retrieved successfully", Some(result));
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/weak-keys",
+    tag = "users",
+    params(
+        WeakKeysQuery
+    ),
+    responses(
+        (status = 200, description = "Weak keys retrieved successfully", body = ApiResponse<WeakKeysSchema>),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+#[axum::debug_handler]
+pub async fn current_user_weak_keys_get(
+    state: State<AppState>,
+    Extension(auth_state): Extension<AuthSchema>,
+    Query(query): Query<WeakKeysQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = &auth_state
+        .user
+        .as_ref()
+        .ok_or_else(|| CustomError::new(StatusCode::UNAUTHORIZED, "User not logged in".into()))?
+        .id;
+
+    let result = get_weak_keys(&state.conn, user_id, query)
+        .await
+        .map_err(ApiError::from)?;
+
+    let response = ApiResponse::success("Weak keys retrieved successfully", Some(result));
+
+    Ok(Json(response))
+}
+
 pub fn create_user_router() -> Router<AppState> {
     Router::new()
         .route("/", post(users_post))
         .route("/{id}", get(users_id_get))
+        .route("/{id}/history", get(users_id_history_get))
+        .route("/{id}/stats", get(users_id_stats_get))
+        .route("/{id}/rating", get(users_id_rating_get))
+        .route("/{id}/achievements", get(users_id_achievements_get))
         .route("/me", get(me_get))
         .route("/me", patch(current_user_update))
+        .route("/me/history", get(current_user_history_get))
+        .route("/me/weak-keys", get(current_user_weak_keys_get))
 }
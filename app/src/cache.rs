@@ -1,9 +1,10 @@
 use std::{
     collections::HashMap,
     sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
 };
 
-use models::schemas::typing::TypingSessionSchema;
+use models::schemas::{leaderboard::LeaderboardSchema, typing::TypingSessionSchema};
 
 use crate::core::TournamentManager;
 
@@ -252,6 +253,15 @@ impl<T> Cache<T> {
         conn.get(id).map(|data| read(data))
     }
 
+    pub fn upsert_data<W, F, O>(&self, id: &str, with: W, update: F) -> O
+    where
+        W: FnOnce() -> T,
+        F: FnOnce(&mut T) -> O,
+    {
+        let mut conn = self.get_connection();
+        update(conn.entry(id.to_owned()).or_insert_with(with))
+    }
+
     pub fn delete_data(&self, id: &str) -> Option<T> {
         let mut conn = self.get_connection();
         conn.remove(id)
@@ -273,6 +283,10 @@ impl<T> Cache<T> {
     pub fn count(&self) -> usize {
         self.get_connection().len()
     }
+
+    pub fn clear(&self) {
+        self.get_connection().clear();
+    }
 }
 
 impl<T: Clone> Cache<T> {
@@ -352,3 +366,49 @@ impl TypingSessionRegistry {
         self.sessions.delete_data(id)
     }
 }
+
+#[derive(Clone)]
+struct CachedLeaderboard {
+    computed_at: Instant,
+    board: Arc<LeaderboardSchema>,
+}
+
+/// Computed leaderboards keyed by their query, so a hot board is only rebuilt once per `ttl`.
+#[derive(Clone)]
+pub struct LeaderboardCache {
+    boards: Cache<CachedLeaderboard>,
+    ttl: Duration,
+}
+
+impl LeaderboardCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            boards: Cache::new(),
+            ttl,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<Arc<LeaderboardSchema>> {
+        self.boards
+            .get_data(key)
+            .filter(|cached| cached.computed_at.elapsed() < self.ttl)
+            .map(|cached| cached.board)
+    }
+
+    pub fn set(&self, key: &str, board: LeaderboardSchema) -> Arc<LeaderboardSchema> {
+        let board = Arc::new(board);
+        self.boards.set_data(
+            key,
+            CachedLeaderboard {
+                computed_at: Instant::now(),
+                board: board.clone(),
+            },
+        );
+        board
+    }
+
+    /// Drops every cached board, e.g. once new results have been recorded.
+    pub fn invalidate(&self) {
+        self.boards.clear();
+    }
+}
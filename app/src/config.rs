@@ -1,13 +1,14 @@
 use jsonwebtoken::{DecodingKey, EncodingKey};
 use lettre::{AsyncSmtpTransport, Tokio1Executor, transport::smtp::authentication::Credentials};
 use openidconnect::{
-    Client, ClientId, ClientSecret, EmptyAdditionalClaims, EndpointMaybeSet, EndpointNotSet,
-    EndpointSet, IssuerUrl, RedirectUrl, StandardErrorResponse,
+    AuthUrl, Client, ClientId, ClientSecret, EmptyAdditionalClaims,
+    EmptyAdditionalProviderMetadata, EndpointMaybeSet, EndpointNotSet, EndpointSet, IssuerUrl,
+    JsonWebKeySetUrl, RedirectUrl, ResponseTypes, StandardErrorResponse,
     core::{
         CoreAuthDisplay, CoreAuthPrompt, CoreClient, CoreErrorResponseType, CoreGenderClaim,
-        CoreJsonWebKey, CoreJweContentEncryptionAlgorithm, CoreProviderMetadata,
-        CoreRevocableToken, CoreRevocationErrorResponse, CoreTokenIntrospectionResponse,
-        CoreTokenResponse,
+        CoreJsonWebKey, CoreJweContentEncryptionAlgorithm, CoreJwsSigningAlgorithm,
+        CoreProviderMetadata, CoreResponseType, CoreRevocableToken, CoreRevocationErrorResponse,
+        CoreSubjectIdentifierType, CoreTokenIntrospectionResponse, CoreTokenResponse,
     },
 };
 
@@ -160,6 +161,41 @@ impl Config {
         Self(Arc::new(v))
     }
 
+    /// A config for tests that needs no environment and no network: Google's
+    /// endpoints are never reached and mail goes to an unused local relay.
+    pub fn for_tests() -> Config {
+        let google_auth_client = CoreClient::from_provider_metadata(
+            CoreProviderMetadata::new(
+                IssuerUrl::new("https://accounts.google.com".to_string()).unwrap(),
+                AuthUrl::new("https://accounts.google.com/o/oauth2/v2/auth".to_string()).unwrap(),
+                JsonWebKeySetUrl::new("https://www.googleapis.com/oauth2/v3/certs".to_string())
+                    .unwrap(),
+                vec![ResponseTypes::new(vec![CoreResponseType::Code])],
+                vec![CoreSubjectIdentifierType::Public],
+                vec![CoreJwsSigningAlgorithm::RsaSsaPkcs1V15Sha256],
+                EmptyAdditionalProviderMetadata {},
+            ),
+            ClientId::new("test-client".to_string()),
+            Some(ClientSecret::new("test-secret".to_string())),
+        )
+        .set_redirect_uri(RedirectUrl::new("http://localhost/callback".to_string()).unwrap());
+
+        let v = ConfigInner {
+            db_url: "sqlite::memory:".to_string(),
+            host: "127.0.0.1".to_string(),
+            port: 3000,
+            allowed_origins: vec!["http://localhost:3000".to_string()],
+            encoding_key: EncodingKey::from_secret(b"test-secret"),
+            decoding_key: DecodingKey::from_secret(b"test-secret"),
+            emailer: "test@example.com".to_string(),
+            transponder: AsyncSmtpTransport::<Tokio1Executor>::unencrypted_localhost(),
+            google_auth_client,
+            http_client: openidconnect::reqwest::Client::new(),
+        };
+
+        Self(Arc::new(v))
+    }
+
     pub fn get_server_url(&self) -> String {
         format!("{}:{}", self.0.host, self.0.port)
     }
@@ -0,0 +1,136 @@
+use chrono::{NaiveDate, TimeDelta};
+
+/// A user's race record, condensed for achievement rules.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AchievementProgress {
+    pub races: u64,
+    pub finished_races: u64,
+    pub wins: u64,
+    pub best_speed: i32,
+    pub perfect_races: u64,
+    /// Consecutive UTC days with at least one race, ending on the day of the latest race.
+    pub day_streak: u32,
+}
+
+/// Counts consecutive race days, fed the days of a user's races newest first.
+#[derive(Debug, Default)]
+pub struct DayStreak {
+    day: Option<NaiveDate>,
+    days: u32,
+}
+
+impl DayStreak {
+    /// Adds the day of the next older race. Returns false once a day was missed,
+    /// as older races can't extend the streak any more.
+    pub fn push(&mut self, day: NaiveDate) -> bool {
+        match self.day {
+            Some(latest) if latest == day => true,
+            Some(latest) if latest - TimeDelta::days(1) != day => false,
+            _ => {
+                self.day = Some(day);
+                self.days += 1;
+                true
+            }
+        }
+    }
+
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+}
+
+pub struct Achievement {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub rule: fn(&AchievementProgress) -> bool,
+}
+
+/// Every badge a user can earn. Ids are persisted, so never rename or reuse one.
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: "first_race",
+        title: "First Race",
+        description: "Complete your first tournament race.",
+        rule: |p| p.finished_races >= 1,
+    },
+    Achievement {
+        id: "first_win",
+        title: "Champion",
+        description: "Finish a tournament in first place.",
+        rule: |p| p.wins >= 1,
+    },
+    Achievement {
+        id: "wpm_100",
+        title: "100 WPM Club",
+        description: "Finish a race at 100 WPM or faster.",
+        rule: |p| p.best_speed >= 100,
+    },
+    Achievement {
+        id: "perfect_10",
+        title: "Flawless",
+        description: "Finish 10 races with perfect accuracy.",
+        rule: |p| p.perfect_races >= 10,
+    },
+    Achievement {
+        id: "streak_7",
+        title: "On a Roll",
+        description: "Race on 7 consecutive days.",
+        rule: |p| p.day_streak >= 7,
+    },
+];
+
+pub fn find_achievement(id: &str) -> Option<&'static Achievement> {
+    ACHIEVEMENTS.iter().find(|achievement| achievement.id == id)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    fn streak(dates: &[&str]) -> u32 {
+        let mut streak = DayStreak::default();
+        for date in dates {
+            if !streak.push(date.parse().unwrap()) {
+                break;
+            }
+        }
+        streak.days()
+    }
+
+    #[test]
+    fn streak_counts_back_from_latest_day() {
+        assert_eq!(streak(&[]), 0);
+        assert_eq!(
+            streak(&[
+                "2025-01-05",
+                "2025-01-05",
+                "2025-01-04",
+                "2025-01-03",
+                "2025-01-01"
+            ]),
+            3
+        );
+    }
+
+    #[test]
+    fn first_race_needs_a_finish() {
+        let first_race = find_achievement("first_race").unwrap();
+        let progress = |races, finished_races| AchievementProgress {
+            races,
+            finished_races,
+            ..Default::default()
+        };
+
+        assert!(!(first_race.rule)(&progress(3, 0)));
+        assert!((first_race.rule)(&progress(3, 1)));
+    }
+
+    #[test]
+    fn achievement_ids_are_unique() {
+        let ids = ACHIEVEMENTS.iter().map(|a| a.id).collect::<BTreeSet<_>>();
+        assert_eq!(ids.len(), ACHIEVEMENTS.len());
+    }
+}
@@ -1,24 +1,168 @@
-use models::schemas::typing::TypingSessionSchema;
-use tracing::{info, warn};
+use chrono::{DateTime, Utc};
+use models::{domains::sea_orm_active_enums::RaceMode, schemas::typing::TypingSessionSchema};
+use tracing::info;
 
-use crate::core::dtos::*;
+use crate::core::{dtos::*, key_stats::KeyStats};
 
+/// Positions handed to and reported by an algorithm count Unicode scalar values
+/// (`char`s) of the original text, never bytes.
 pub trait TypingAlgorithm {
     fn handle_type(
         &self,
         session: &mut TypingSessionSchema,
         input: &[char],
-        original: &[u8],
+        original: &[char],
+        key_stats: &mut KeyStats,
     ) -> Result<PartialParticipantData, WsFailurePayload>;
 
     fn handle_progress(
         &self,
         session: &mut TypingSessionSchema,
         progress: ProgressEventPayload,
-        original: &[u8],
+        original: &[char],
+        key_stats: &mut KeyStats,
     ) -> Result<PartialParticipantData, WsFailurePayload>;
 }
 
+/// The algorithm races of the given mode are typed with.
+pub fn typing_algorithm(mode: RaceMode) -> &'static (dyn TypingAlgorithm + Sync + Send) {
+    match mode {
+        RaceMode::Standard => &ZeroProceed,
+        RaceMode::TimeAttack => &TimeAttack,
+    }
+}
+
+/// Applies one typed character. Backspace steps back, but never past a space
+/// that was typed correctly, so finished words stay locked in.
+fn apply_char(
+    session: &mut TypingSessionSchema,
+    current_char: char,
+    original: &[char],
+    key_stats: &mut KeyStats,
+    latency_ms: Option<u64>,
+) {
+    if current_char == '\u{8}' {
+        if session.current_position > session.correct_position {
+            session.current_position -= 1;
+        } else if session.current_position > 0 && original[session.current_position - 1] != ' ' {
+            session.correct_position -= 1;
+            session.current_position -= 1;
+        }
+        return;
+    }
+
+    if session.current_position >= original.len() {
+        return;
+    }
+
+    session.total_keystrokes += 1;
+
+    if session.current_position == session.correct_position {
+        let expected_char = original[session.current_position];
+        let matched = current_char == expected_char;
+        let previous_char = session.current_position.checked_sub(1).map(|i| original[i]);
+        key_stats.record(expected_char, previous_char, !matched, latency_ms);
+
+        if matched {
+            session.correct_position += 1;
+        }
+    }
+    session.current_position += 1;
+}
+
+fn check_progress(
+    session: &TypingSessionSchema,
+    progress: &ProgressEventPayload,
+    original: &[char],
+) -> Result<(), WsFailurePayload> {
+    let text_len = original.len();
+
+    if progress.current_position > text_len
+        || progress.correct_position > text_len
+        || progress.correct_position > progress.current_position
+    {
+        return Err(WsFailurePayload::new(2212, "Invalid progress data."));
+    }
+
+    if session.ended_at.is_some() {
+        return Err(WsFailurePayload::new(2211, "Your session has ended."));
+    }
+
+    Ok(())
+}
+
+/// Attributes the keystrokes behind a progress update, which carries positions rather
+/// than keys. Characters the correct position moves over count as hits; a caret left
+/// ahead of it counts as one miss of the expected key, unless the previous update
+/// already showed that miss.
+fn record_progress(
+    session: &TypingSessionSchema,
+    progress: &ProgressEventPayload,
+    original: &[char],
+    key_stats: &mut KeyStats,
+    now: DateTime<Utc>,
+) {
+    let keystrokes = progress.total_keystrokes - session.total_keystrokes;
+    let latency_ms = key_stats.start_batch(now, keystrokes.max(0) as usize);
+    let previous_char = |position: usize| position.checked_sub(1).map(|i| original[i]);
+
+    // Empty when the update stepped back over correct characters.
+    let passed = original
+        .get(session.correct_position..progress.correct_position)
+        .unwrap_or_default();
+    for (position, &expected) in (session.correct_position..).zip(passed) {
+        key_stats.record(expected, previous_char(position), false, latency_ms);
+    }
+
+    let missed = progress.current_position > progress.correct_position;
+    let already_missed = session.current_position > session.correct_position
+        && session.correct_position == progress.correct_position;
+    if missed && !already_missed {
+        let position = progress.correct_position;
+        key_stats.record(
+            original[position],
+            previous_char(position),
+            true,
+            latency_ms,
+        );
+    }
+}
+
+/// Refreshes speed and accuracy and reports the session's progress.
+fn session_changes(
+    session: &mut TypingSessionSchema,
+    now: DateTime<Utc>,
+) -> PartialParticipantData {
+    if let Some(started_at) = session.started_at {
+        let end_time = session.ended_at.unwrap_or(now);
+        let duration = end_time.signed_duration_since(started_at);
+        let minutes_elapsed = (duration.num_milliseconds() as f32 / 60000.0).max(0.0001);
+
+        session.current_speed = (session.correct_position as f32 / 5.0 / minutes_elapsed).round();
+        session.current_accuracy = if session.total_keystrokes > 0 {
+            ((session.correct_position as f32 / session.total_keystrokes as f32) * 100.0)
+                .round()
+                .clamp(0.0, 100.0)
+        } else {
+            100.0
+        };
+    } else {
+        session.current_speed = 0.0;
+        session.current_accuracy = 100.0;
+    }
+
+    PartialParticipantData {
+        current_position: Some(session.current_position),
+        correct_position: Some(session.correct_position),
+        total_keystrokes: Some(session.total_keystrokes),
+        current_speed: Some(session.current_speed),
+        current_accuracy: Some(session.current_accuracy),
+        started_at: session.started_at,
+        ended_at: session.ended_at,
+    }
+}
+
+/// Standard races: the session ends once the whole text is typed correctly.
 #[derive(Clone, Copy)]
 pub struct ZeroProceed;
 
@@ -27,50 +171,22 @@ impl TypingAlgorithm for ZeroProceed {
         &self,
         session: &mut TypingSessionSchema,
         input: &[char],
-        original: &[u8],
+        original: &[char],
+        key_stats: &mut KeyStats,
     ) -> Result<PartialParticipantData, WsFailurePayload> {
-        let now = chrono::Utc::now();
-        if session.started_at.is_none() {
-            session.started_at = Some(now);
+        if session.ended_at.is_some() {
+            return Err(WsFailurePayload::new(2211, "Your session has ended."));
         }
 
-        let text_len = input.len();
+        let now = Utc::now();
+        session.started_at.get_or_insert(now);
 
-        for &current_char in input {
-            if session.correct_position >= text_len {
-                warn!(user_id=%session.member.id, "Received typing input after session ended. Ignoring.");
-                break;
-            }
+        let latency_ms = key_stats.start_batch(now, input.len());
 
-            if current_char == '\u{8}' {
-                // Backspace character (`\b` or unicode backspace)
-                if session.current_position > session.correct_position {
-                    session.current_position -= 1;
-                } else if session.current_position == session.correct_position
-                    && session.current_position > 0
-                {
-                    if original[session.current_position - 1] != b' ' {
-                        session.correct_position -= 1;
-                        session.current_position -= 1;
-                    }
-                }
-                // If current_position is 0, backspace does nothing.
-                // No change to total_keystrokes for backspace.
-            } else {
-                session.total_keystrokes += 1;
-
-                if session.current_position < text_len {
-                    let expected_char = original[session.current_position];
-                    if session.current_position == session.correct_position
-                        && (current_char as u32) == (expected_char as u32)
-                    {
-                        session.correct_position += 1;
-                    }
-                    session.current_position += 1;
-                }
-            }
+        for &current_char in input {
+            apply_char(session, current_char, original, key_stats, latency_ms);
 
-            if session.correct_position == text_len && session.ended_at.is_none() {
+            if session.correct_position == original.len() {
                 session.ended_at = Some(now);
                 session.current_position = session.correct_position;
                 info!(member_id = %session.member.id, tournament_id = %session.tournament_id, "User finished typing challenge");
@@ -78,105 +194,202 @@ impl TypingAlgorithm for ZeroProceed {
             }
         }
 
-        if let Some(started_at) = session.started_at {
-            let end_time = session.ended_at.unwrap_or(now);
-            let duration = end_time.signed_duration_since(started_at);
+        Ok(session_changes(session, now))
+    }
 
-            let minutes_elapsed = (duration.num_milliseconds() as f32 / 60000.0).max(0.0001);
+    fn handle_progress(
+        &self,
+        session: &mut TypingSessionSchema,
+        progress: ProgressEventPayload,
+        original: &[char],
+        key_stats: &mut KeyStats,
+    ) -> Result<PartialParticipantData, WsFailurePayload> {
+        check_progress(session, &progress, original)?;
 
-            session.current_speed =
-                (session.correct_position as f32 / 5.0 / minutes_elapsed).round();
+        let now = Utc::now();
+        session.started_at.get_or_insert(now);
+        record_progress(session, &progress, original, key_stats, now);
 
-            session.current_accuracy = if session.total_keystrokes > 0 {
-                ((session.correct_position as f32 / session.total_keystrokes as f32) * 100.0)
-                    .round()
-                    .clamp(0.0, 100.0)
-            } else {
-                100.0
-            };
-        } else {
-            session.current_speed = 0.0;
-            session.current_accuracy = 100.0;
+        session.current_position = progress.current_position;
+        session.correct_position = progress.correct_position;
+        session.total_keystrokes = progress.total_keystrokes;
+
+        if session.correct_position == original.len() {
+            session.ended_at = Some(now);
+            info!(
+                member_id = %session.member.id,
+                tournament_id = %session.tournament_id,
+                "User finished typing challenge via progress update"
+            );
         }
 
-        Ok(PartialParticipantData {
-            current_position: Some(session.current_position),
-            correct_position: Some(session.correct_position),
-            total_keystrokes: Some(session.total_keystrokes),
-            current_speed: Some(session.current_speed),
-            current_accuracy: Some(session.current_accuracy),
-            started_at: session.started_at,
-            ended_at: session.ended_at,
-        })
+        Ok(session_changes(session, now))
     }
+}
 
-    fn handle_progress(
+/// Time-attack races: the text keeps growing and nobody finishes it. Sessions only
+/// end when the race clock runs out, so input simply stops at the end of the text
+/// received so far.
+#[derive(Clone, Copy)]
+pub struct TimeAttack;
+
+impl TypingAlgorithm for TimeAttack {
+    fn handle_type(
         &self,
         session: &mut TypingSessionSchema,
-        progress: ProgressEventPayload,
-        original: &[u8],
+        input: &[char],
+        original: &[char],
+        key_stats: &mut KeyStats,
     ) -> Result<PartialParticipantData, WsFailurePayload> {
-        let now = chrono::Utc::now();
-        let text_len = original.len();
-
-        let ProgressEventPayload {
-            correct_position,
-            current_position,
-            total_keystrokes,
-            rid: _,
-        } = progress;
-
-        if current_position > text_len
-            || correct_position > text_len
-            || correct_position > current_position
-        {
-            return Err(WsFailurePayload::new(2212, "Invalid progress data."));
-        }
-
         if session.ended_at.is_some() {
             return Err(WsFailurePayload::new(2211, "Your session has ended."));
         }
 
-        if session.started_at.is_none() {
-            session.started_at = Some(now);
+        let now = Utc::now();
+        session.started_at.get_or_insert(now);
+
+        let latency_ms = key_stats.start_batch(now, input.len());
+
+        for &current_char in input {
+            apply_char(session, current_char, original, key_stats, latency_ms);
         }
 
-        session.current_position = current_position;
-        session.correct_position = correct_position;
-        session.total_keystrokes = total_keystrokes;
-
-        if let Some(started_at) = session.started_at {
-            let duration = now.signed_duration_since(started_at);
-            let minutes_elapsed = (duration.num_milliseconds() as f32 / 60000.0).max(0.0001);
-
-            session.current_speed =
-                (session.correct_position as f32 / 5.0 / minutes_elapsed).round();
-            session.current_accuracy = if session.total_keystrokes > 0 {
-                ((session.correct_position as f32 / session.total_keystrokes as f32) * 100.0)
-                    .round()
-                    .clamp(0.0, 100.0)
-            } else {
-                100.0
+        Ok(session_changes(session, now))
+    }
+
+    fn handle_progress(
+        &self,
+        session: &mut TypingSessionSchema,
+        progress: ProgressEventPayload,
+        original: &[char],
+        key_stats: &mut KeyStats,
+    ) -> Result<PartialParticipantData, WsFailurePayload> {
+        check_progress(session, &progress, original)?;
+
+        let now = Utc::now();
+        session.started_at.get_or_insert(now);
+        record_progress(session, &progress, original, key_stats, now);
+
+        session.current_position = progress.current_position;
+        session.correct_position = progress.correct_position;
+        session.total_keystrokes = progress.total_keystrokes;
+
+        Ok(session_changes(session, now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use models::schemas::user::TournamentRoomMember;
+
+    use super::*;
+
+    fn session() -> TypingSessionSchema {
+        let member = TournamentRoomMember {
+            id: "m".into(),
+            user: None,
+            participant: true,
+            rating: None,
+            user_id: None,
+            team: None,
+        };
+        TypingSessionSchema::new(member, "t".into())
+    }
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn positions_count_characters_not_bytes() {
+        let text = chars("café 東京 👋");
+        let mut session = session();
+        let mut key_stats = KeyStats::default();
+
+        ZeroProceed
+            .handle_type(&mut session, &chars("café 東"), &text, &mut key_stats)
+            .unwrap();
+        assert_eq!(session.correct_position, 6);
+        assert!(session.ended_at.is_none());
+
+        // A wrong character followed by a backspace leaves the position unchanged.
+        ZeroProceed
+            .handle_type(&mut session, &['x', '\u{8}'], &text, &mut key_stats)
+            .unwrap();
+        assert_eq!(session.current_position, 6);
+
+        let changes = ZeroProceed
+            .handle_type(&mut session, &chars("京 👋"), &text, &mut key_stats)
+            .unwrap();
+        assert_eq!(changes.correct_position, Some(9));
+        assert!(session.ended_at.is_some());
+    }
+
+    #[test]
+    fn time_attack_never_finishes_and_stops_at_the_end_of_the_text() {
+        let mut session = session();
+        let mut key_stats = KeyStats::default();
+
+        let changes = TimeAttack
+            .handle_type(
+                &mut session,
+                &['a', 'b', 'x', 'y'],
+                &chars("ab"),
+                &mut key_stats,
+            )
+            .unwrap();
+
+        assert_eq!(changes.correct_position, Some(2));
+        assert_eq!(changes.current_position, Some(2));
+        assert_eq!(session.total_keystrokes, 2);
+        assert!(session.ended_at.is_none());
+
+        // Once the text was extended the same session carries on.
+        TimeAttack
+            .handle_type(
+                &mut session,
+                &[' ', 'c', 'x'],
+                &chars("ab cd"),
+                &mut key_stats,
+            )
+            .unwrap();
+
+        assert_eq!(session.correct_position, 4);
+        assert_eq!(session.current_position, 5);
+    }
+
+    #[test]
+    fn progress_counts_passed_characters_and_the_key_left_mistyped() {
+        let text = chars("the cat");
+        let mut session = session();
+        let mut key_stats = KeyStats::default();
+        let progress =
+            |correct_position, current_position, total_keystrokes| ProgressEventPayload {
+                correct_position,
+                current_position,
+                total_keystrokes,
+                rid: 0,
             };
-        }
 
-        if session.correct_position == text_len && session.ended_at.is_none() {
-            session.ended_at = Some(now);
-            info!(
-                member_id = %session.member.id,
-                tournament_id = %session.tournament_id,
-                "User finished typing challenge via progress update"
-            );
-        }
+        ZeroProceed
+            .handle_progress(&mut session, progress(4, 6, 6), &text, &mut key_stats)
+            .unwrap();
+        // Still stuck on the same key: the miss is not counted twice.
+        ZeroProceed
+            .handle_progress(&mut session, progress(4, 5, 8), &text, &mut key_stats)
+            .unwrap();
+        // Stepping back over correct characters records nothing.
+        ZeroProceed
+            .handle_progress(&mut session, progress(3, 3, 9), &text, &mut key_stats)
+            .unwrap();
+        ZeroProceed
+            .handle_progress(&mut session, progress(7, 7, 13), &text, &mut key_stats)
+            .unwrap();
 
-        Ok(PartialParticipantData {
-            current_position: Some(session.current_position),
-            correct_position: Some(session.correct_position),
-            total_keystrokes: Some(session.total_keystrokes),
-            current_speed: Some(session.current_speed),
-            current_accuracy: Some(session.current_accuracy),
-            started_at: session.started_at,
-            ended_at: session.ended_at,
-        })
+        let c = key_stats.keys[&'c'];
+        assert_eq!((c.attempts, c.errors), (2, 1));
+        assert_eq!(key_stats.keys[&'t'].attempts, 2);
+        assert_eq!(key_stats.bigrams[&(' ', 'c')].errors, 1);
+        assert!(session.ended_at.is_some());
     }
 }
@@ -0,0 +1,83 @@
+use models::schemas::typing::TypingSessionSchema;
+
+/// Where a tournament's round state machine stands. Round `0` means no race
+/// has started yet; `racing` is false between rounds and after the last one.
+#[derive(Debug, Default)]
+pub struct BracketState {
+    pub round: i32,
+    pub racing: bool,
+    /// Members knocked out so far, one group per round, each ranked best first.
+    pub eliminated: Vec<Vec<TypingSessionSchema>>,
+}
+
+/// Splits a round's ranking into the members who advance and the bottom
+/// `eliminations` who drop out. At least one member always advances.
+pub fn split_round(
+    mut ranked: Vec<TypingSessionSchema>,
+    eliminations: usize,
+) -> (Vec<TypingSessionSchema>, Vec<TypingSessionSchema>) {
+    let advancing = ranked.len().saturating_sub(eliminations).max(1);
+    let eliminated = ranked.split_off(advancing.min(ranked.len()));
+    (ranked, eliminated)
+}
+
+/// Final placement of a bracket: the finalists in the order they are given,
+/// then the members eliminated in later rounds ahead of those eliminated earlier.
+pub fn bracket_standings(
+    finalists: Vec<TypingSessionSchema>,
+    eliminated: Vec<Vec<TypingSessionSchema>>,
+) -> Vec<TypingSessionSchema> {
+    finalists
+        .into_iter()
+        .chain(eliminated.into_iter().rev().flatten())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use models::schemas::user::TournamentRoomMember;
+
+    use super::*;
+
+    fn session(id: &str) -> TypingSessionSchema {
+        let member = TournamentRoomMember {
+            id: id.into(),
+            user: None,
+            participant: true,
+            rating: None,
+            user_id: None,
+            team: None,
+        };
+        TypingSessionSchema::new(member, "t".into())
+    }
+
+    fn ids(sessions: &[TypingSessionSchema]) -> Vec<&str> {
+        sessions.iter().map(|s| s.member.id.as_str()).collect()
+    }
+
+    #[test]
+    fn bottom_members_are_eliminated_but_one_always_advances() {
+        let ranked = vec![session("a"), session("b"), session("c")];
+
+        let (advancing, eliminated) = split_round(ranked.clone(), 2);
+        assert_eq!(ids(&advancing), ["a"]);
+        assert_eq!(ids(&eliminated), ["b", "c"]);
+
+        let (advancing, eliminated) = split_round(ranked, 5);
+        assert_eq!(ids(&advancing), ["a"]);
+        assert_eq!(ids(&eliminated), ["b", "c"]);
+    }
+
+    #[test]
+    fn later_eliminations_place_higher() {
+        let standings = bracket_standings(
+            vec![session("a"), session("b")],
+            vec![
+                vec![session("e"), session("f")],
+                vec![session("c"), session("d")],
+            ],
+        );
+
+        assert_eq!(ids(&standings), ["a", "b", "c", "d", "e", "f"]);
+    }
+}
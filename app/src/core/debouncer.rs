@@ -25,7 +25,8 @@ pub struct Debouncer {
 }
 
 struct DebouncerInner {
-    tx: mpsc::UnboundedSender<()>,
+    // Taken on shutdown: dropping the only sender is what stops the worker.
+    tx: std::sync::Mutex<Option<mpsc::UnboundedSender<()>>>,
     // The handle must be optional so we can `take()` it on shutdown.
     shutdown_handle: std::sync::Mutex<Option<JoinHandle<()>>>,
 }
@@ -65,7 +66,7 @@ impl Debouncer {
         let shutdown_handle = tokio::spawn(worker_loop(action, config, rx));
 
         let inner = Arc::new(DebouncerInner {
-            tx,
+            tx: std::sync::Mutex::new(Some(tx)),
             shutdown_handle: std::sync::Mutex::new(Some(shutdown_handle)),
         });
 
@@ -81,7 +82,9 @@ impl Debouncer {
         // Send a message to the worker task. We ignore the result; if the send fails,
         // it means the receiver (the worker task) has already been dropped, which
         // implies the debouncer is shut down. There's nothing to do in that case.
-        let _ = self.inner.tx.send(());
+        if let Some(tx) = &*self.inner.tx.lock().unwrap() {
+            let _ = tx.send(());
+        }
     }
 
     /// Shuts down the debouncer gracefully.
@@ -92,8 +95,8 @@ impl Debouncer {
         let handle = self.inner.shutdown_handle.lock().unwrap().take();
 
         if let Some(handle) = handle {
-            // Drop our sender instance by closing the channel.
-            self.inner.tx.clone().closed().await;
+            // Dropping the only sender closes the channel and lets the worker finish.
+            self.inner.tx.lock().unwrap().take();
 
             // Wait for the worker task to complete its final execution and exit.
             let _ = handle.await;
@@ -1,5 +1,8 @@
 use chrono::{DateTime, Utc};
-use models::schemas::user::TournamentRoomMember;
+use models::domains::sea_orm_active_enums::{RaceMode, TeamScoring};
+use models::schemas::{
+    achievement::AchievementSchema, tournament::TeamStandingSchema, user::TournamentRoomMember,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Debug, Clone)]
@@ -66,6 +69,9 @@ pub struct UpdateMePayload {
 #[derive(Serialize, Debug, Clone)]
 pub struct UpdateAllPayload<'a> {
     pub updates: Vec<PartialParticipantDataForUpdate<'a>>,
+    /// Live team standings, only sent for team tournaments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Vec<TeamStandingSchema>>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -81,6 +87,12 @@ pub struct TournamentData {
     pub ended_at: Option<DateTime<Utc>>,
     pub scheduled_end: Option<DateTime<Utc>>,
     pub text: Option<String>,
+    pub max_participants: Option<i32>,
+    pub team_scoring: Option<TeamScoring>,
+    pub mode: RaceMode,
+    pub rounds: i32,
+    /// Round currently running or last run, `0` before the start.
+    pub round: i32,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -100,6 +112,17 @@ pub struct PartialTournamentData {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+
+    /// Text appended to a time-attack race, starting at `offset`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_chunk: Option<TextChunk>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TextChunk {
+    pub offset: usize,
+    pub text: String,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -109,6 +132,27 @@ pub struct JoinSuccessPayload {
     pub member: TournamentRoomMember,
     pub participants: Vec<ParticipantData>,
     pub noauth: String,
+    /// 1-based position on the waitlist when the tournament was full on join.
+    pub waitlist_position: Option<usize>,
+    /// Ids of the participants marked ready in the lobby.
+    pub ready: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PracticeStartedPayload {
+    pub id: String,
+    pub text: String,
+    pub member: TournamentRoomMember,
+    pub noauth: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PracticeEndedPayload {
+    pub participant: ParticipantData,
+    /// Whether the result went to the member's history; guests have none.
+    pub saved: bool,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -117,10 +161,32 @@ pub struct ParticipantJoinedPayload {
     pub participant: ParticipantData,
 }
 
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WaitlistPromotedPayload {
+    pub participant: ParticipantData,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WaitlistPositionPayload {
+    pub position: usize,
+}
+
 #[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticipantLeftPayload {
     pub member_id: String,
+    pub reason: LeaveReason,
+}
+
+/// Why a member left the room.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LeaveReason {
+    Left,
+    Kicked,
+    Banned,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -135,6 +201,60 @@ pub struct UpdateDataPayload {
     pub updates: PartialTournamentData,
 }
 
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundStartedPayload {
+    pub round: i32,
+    pub rounds: i32,
+    pub started_at: DateTime<Utc>,
+    pub scheduled_end: DateTime<Utc>,
+    pub text: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundEndedPayload {
+    pub round: i32,
+    /// Member ids moving on, best first. After the last round these are the finalists.
+    pub advancing: Vec<String>,
+    /// Member ids knocked out this round, best first.
+    pub eliminated: Vec<String>,
+    /// When the next round starts, `None` after the last round.
+    pub next_round_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AchievementUnlockedPayload {
+    pub achievements: Vec<AchievementSchema>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ReadyEventPayload {
+    pub ready: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantReadyPayload {
+    pub member_id: String,
+    pub ready: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StartSuccessPayload {
+    pub starts_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownTickPayload {
+    /// Whole seconds left before the text is revealed.
+    pub remaining: i64,
+    pub starts_at: DateTime<Utc>,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct TypeEventPayload {
     pub character: char,
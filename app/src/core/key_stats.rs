@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+/// Gaps longer than this are treated as pauses and left out of latency figures.
+const MAX_KEY_LATENCY_MS: i64 = 3_000;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KeyTally {
+    pub attempts: u32,
+    pub errors: u32,
+    pub latency_ms: u64,
+    pub latency_samples: u32,
+}
+
+impl KeyTally {
+    fn record(&mut self, missed: bool, latency_ms: Option<u64>) {
+        self.attempts += 1;
+        if missed {
+            self.errors += 1;
+        }
+        if let Some(latency_ms) = latency_ms {
+            self.latency_ms += latency_ms;
+            self.latency_samples += 1;
+        }
+    }
+}
+
+/// Per-key and per-bigram error tallies of one participant's race.
+///
+/// Only keystrokes typed at the first unmatched position are attributed: once the caret
+/// runs ahead of the last correct character, further input says nothing about the
+/// expected key. Bigrams pair the expected character with the one before it.
+#[derive(Clone, Debug, Default)]
+pub struct KeyStats {
+    last_input_at: Option<DateTime<Utc>>,
+    pub keys: HashMap<char, KeyTally>,
+    pub bigrams: HashMap<(char, char), KeyTally>,
+}
+
+impl KeyStats {
+    /// Marks the arrival of a batch of `len` keystrokes and returns the latency to credit
+    /// to each of them. Individual timings inside a batch are not known, so the gap since
+    /// the previous batch is spread evenly.
+    pub fn start_batch(&mut self, at: DateTime<Utc>, len: usize) -> Option<u64> {
+        let previous = self.last_input_at.replace(at)?;
+        let gap_ms = at.signed_duration_since(previous).num_milliseconds() / len.max(1) as i64;
+
+        (0..=MAX_KEY_LATENCY_MS)
+            .contains(&gap_ms)
+            .then_some(gap_ms as u64)
+    }
+
+    pub fn record(
+        &mut self,
+        expected: char,
+        previous: Option<char>,
+        missed: bool,
+        latency_ms: Option<u64>,
+    ) {
+        self.keys
+            .entry(expected)
+            .or_default()
+            .record(missed, latency_ms);
+
+        if let Some(previous) = previous {
+            self.bigrams
+                .entry((previous, expected))
+                .or_default()
+                .record(missed, latency_ms);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use super::*;
+
+    #[test]
+    fn batch_latency_is_spread_and_pauses_ignored() {
+        let start = Utc::now();
+        let mut stats = KeyStats::default();
+
+        assert_eq!(stats.start_batch(start, 1), None);
+        assert_eq!(
+            stats.start_batch(start + TimeDelta::milliseconds(400), 2),
+            Some(200)
+        );
+        assert_eq!(stats.start_batch(start + TimeDelta::seconds(10), 1), None);
+    }
+
+    #[test]
+    fn misses_count_towards_key_and_bigram() {
+        let mut stats = KeyStats::default();
+        stats.record('h', Some('t'), false, Some(120));
+        stats.record('h', Some('t'), true, None);
+        stats.record('t', None, false, None);
+
+        let key = stats.keys[&'h'];
+        assert_eq!((key.attempts, key.errors), (2, 1));
+        assert_eq!((key.latency_ms, key.latency_samples), (120, 1));
+        assert_eq!(stats.bigrams[&('t', 'h')].errors, 1);
+        assert_eq!(stats.bigrams.len(), 1);
+    }
+}
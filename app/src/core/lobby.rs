@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+
+/// Length of the countdown that precedes every start, early or scheduled.
+pub const START_COUNTDOWN_SECS: i64 = 5;
+
+/// The pre-race lobby: who is ready, and when the race starts once a
+/// countdown is running.
+#[derive(Debug, Default)]
+pub struct LobbyState {
+    pub ready: HashSet<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+}
+
+impl LobbyState {
+    /// Whether there is at least one participant and every one of them is ready.
+    pub fn all_ready(&self, participant_ids: &[String]) -> bool {
+        !participant_ids.is_empty() && participant_ids.iter().all(|id| self.ready.contains(id))
+    }
+}
+
+/// The whole seconds left before `starts_at`, capped at the countdown length.
+/// A countdown joined late skips the ticks that are already past.
+pub fn countdown_seconds(starts_at: DateTime<Utc>, now: DateTime<Utc>) -> i64 {
+    let remaining_ms = (starts_at - now).num_milliseconds();
+    (remaining_ms + 999)
+        .div_euclid(1000)
+        .clamp(0, START_COUNTDOWN_SECS)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use super::*;
+
+    #[test]
+    fn everyone_must_be_ready() {
+        let mut lobby = LobbyState::default();
+        let participants = vec!["a".to_string(), "b".to_string()];
+
+        assert!(!lobby.all_ready(&[]));
+        lobby.ready.insert("a".into());
+        assert!(!lobby.all_ready(&participants));
+        lobby.ready.insert("b".into());
+        assert!(lobby.all_ready(&participants));
+    }
+
+    #[test]
+    fn countdown_rounds_up_and_is_capped() {
+        let now = Utc::now();
+
+        assert_eq!(countdown_seconds(now + TimeDelta::seconds(60), now), 5);
+        assert_eq!(
+            countdown_seconds(now + TimeDelta::milliseconds(2100), now),
+            3
+        );
+        assert_eq!(countdown_seconds(now + TimeDelta::seconds(2), now), 2);
+        assert_eq!(countdown_seconds(now - TimeDelta::seconds(1), now), 0);
+    }
+}
@@ -1,16 +1,21 @@
 use crate::core::{algorithm::*, dtos::*};
 use anyhow::Result;
-use chrono::{TimeDelta, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use models::{
-    params::tournament::UpdateTournamentParams,
+    domains::sea_orm_active_enums::RaceMode,
+    params::tournament::{MAX_TEAM_NAME_LENGTH, UpdateTournamentParams},
     schemas::{
         tournament::{TournamentLiveData, TournamentSchema, TournamentSession},
         typing::{TournamentStatus, TypingSessionSchema},
         user::TournamentRoomMember,
     },
 };
+use sea_orm::{DbConn, DbErr};
 use socketioxide::extract::{Data, SocketRef};
 use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
     sync::{Arc, RwLock},
     time::Duration,
 };
@@ -20,35 +25,76 @@ use tracing::{error, info, warn};
 use crate::{
     cache::Cache,
     core::{
+        bracket::{BracketState, bracket_standings, split_round},
         debouncer::{Debouncer, DebouncerConfig},
+        key_stats::KeyStats,
+        lobby::{LobbyState, START_COUNTDOWN_SECS, countdown_seconds},
         moderation::FrequencyMonitor,
+        replay::ReplayLog,
+        standings::{rank_by_correct_chars, rank_sessions},
+        teams::rank_teams,
         timeout::TimeoutMonitor,
     },
-    persistence::{text::generate_text, tournaments::update_tournament},
+    persistence::{
+        text::{GeneratedText, generate_text, race_seed},
+        tournament_results::record_final_results,
+        tournament_texts::{TextStretch, record_tournament_texts},
+        tournaments::update_tournament,
+    },
     state::AppState,
 };
 
-const JOIN_DEADLINE: Duration = Duration::from_secs(15);
-const INACTIVITY_TIMEOUT_DURATION: Duration = Duration::from_secs(30);
-
 const DEBOUNCE_DURATION: Duration = Duration::from_millis(250);
 const MAX_PROCESS_WAIT: Duration = Duration::from_millis(800);
 const MAX_PROCESS_STACK_SIZE: usize = 5;
 
+/// Pause between the rounds of an elimination bracket.
+const ROUND_COUNTDOWN: TimeDelta = TimeDelta::seconds(10);
+
+/// A time-attack text is extended once a participant gets this close to its end.
+const TIME_ATTACK_LOOKAHEAD: usize = 200;
+
 const UPDATE_ALL_DEBOUNCE_DURATION: Duration = Duration::from_millis(1000);
 const UPDATE_ALL_MAX_STACK_SIZE: usize = 20;
 const UPDATE_ALL_MAX_WAIT: Duration = Duration::from_secs(3);
 
 struct TournamentManagerInner {
     tournament_id: Arc<String>,
-    tournament_meta: Arc<TournamentSchema>,
+    tournament_meta: RwLock<Arc<TournamentSchema>>,
     tournament_session_state: Mutex<TournamentSession>,
     participants: Cache<TypingSessionSchema>,
+    replays: Cache<ReplayLog>,
+    key_stats: Cache<KeyStats>,
+    /// Members waiting for a seat, in the order they will be promoted.
+    waitlist: Mutex<VecDeque<Arc<TournamentRoomMember>>>,
+    bracket: Mutex<BracketState>,
+    lobby: Mutex<LobbyState>,
     app_state: AppState,
     typing_text: RwLock<Arc<String>>,
+    /// Seed of the race texts. Tournaments created before seeds were stored get
+    /// one that is never saved, so their texts can't be regenerated.
+    text_seed: i64,
+    /// How the texts raced so far were generated, stored once the tournament ends.
+    text_stretches: std::sync::Mutex<Vec<TextStretch>>,
 }
 
 impl TournamentManagerInner {
+    fn meta(&self) -> Arc<TournamentSchema> {
+        self.tournament_meta.read().unwrap().clone()
+    }
+
+    fn algorithm(&self) -> &'static (dyn TypingAlgorithm + Sync + Send) {
+        typing_algorithm(self.meta().mode)
+    }
+
+    /// Ranks the current participants the way the race mode scores them.
+    fn ranked_participants(&self) -> Vec<TypingSessionSchema> {
+        match self.meta().mode {
+            RaceMode::Standard => rank_sessions(self.participants.values()),
+            RaceMode::TimeAttack => rank_by_correct_chars(self.participants.values()),
+        }
+    }
+
     async fn broadcast_update_data(self: &Arc<Self>, start: bool) {
         let update_data_payload = {
             let (started_at, ended_at) = {
@@ -68,6 +114,7 @@ impl TournamentManagerInner {
                     } else {
                         None
                     },
+                    text_chunk: None,
                 },
             }
         };
@@ -85,7 +132,6 @@ impl TournamentManagerInner {
 
 #[derive(Clone)]
 pub struct TournamentManager {
-    algorithm: Arc<dyn TypingAlgorithm + Sync + Send>,
     inner: Arc<TournamentManagerInner>,
     update_all_broadcaster: Debouncer,
 }
@@ -110,43 +156,185 @@ impl TournamentManager {
 
         let inner_manager_state = Arc::new(TournamentManagerInner {
             tournament_id: tournament_id_arc.clone(),
-            tournament_meta: Arc::new(tournament_schema.clone()),
+            tournament_meta: RwLock::new(Arc::new(tournament_schema.clone())),
             tournament_session_state: initial_session_state,
             participants,
+            replays: Cache::new(),
+            key_stats: Cache::new(),
+            waitlist: Mutex::new(VecDeque::new()),
+            bracket: Mutex::new(BracketState::default()),
+            lobby: Mutex::new(LobbyState::default()),
             app_state: app_state.clone(),
             typing_text: RwLock::new(typing_text_arc),
+            text_seed: tournament_schema.text_seed.unwrap_or_else(rand::random),
+            text_stretches: std::sync::Mutex::new(Vec::new()),
         });
 
         let update_all_broadcaster =
             Self::create_update_all_broadcaster(inner_manager_state.clone());
 
         let manager = Self {
-            algorithm: Arc::new(ZeroProceed),
             inner: inner_manager_state,
             update_all_broadcaster: update_all_broadcaster.clone(),
         };
 
-        {
-            let manager = manager.clone();
-            let tournament_id = tournament_schema.id.clone();
-            let scheduled_for = tournament_schema.scheduled_for;
+        manager.schedule_start(tournament_schema.scheduled_for);
 
-            crate::scheduler::schedule_new_task(
-                async move {
-                    manager.execute_tournament_start_logic().await;
-                },
-                scheduled_for,
-            )
+        manager
+    }
+
+    fn schedule_start(&self, scheduled_for: DateTime<Utc>) {
+        let manager = self.clone();
+        let start_task = async move {
+            // A reschedule leaves the previous task behind; only the task
+            // matching the current `scheduled_for` may start the race.
+            if manager.inner.meta().scheduled_for != scheduled_for {
+                return;
+            }
+            if manager.begin_countdown(scheduled_for).await {
+                manager.run_countdown(scheduled_for).await;
+            }
+        };
+
+        let countdown_at = scheduled_for - TimeDelta::seconds(START_COUNTDOWN_SECS);
+        if countdown_at <= Utc::now() && Utc::now() < scheduled_for {
+            tokio::spawn(start_task);
+            return;
+        }
+
+        crate::scheduler::schedule_new_task(start_task, countdown_at)
             .inspect_err(|e| {
                 error!(
                     "Failed to schedule start task for tournament {}: {}",
-                    tournament_id, e
+                    self.inner.tournament_id, e
                 );
             })
             .ok();
+    }
+
+    /// Claims the lobby for a countdown to `starts_at`. Fails if one is already
+    /// running.
+    async fn begin_countdown(&self, starts_at: DateTime<Utc>) -> bool {
+        let mut lobby_guard = self.inner.lobby.lock().await;
+        if lobby_guard.starts_at.is_some() {
+            return false;
         }
+        lobby_guard.starts_at = Some(starts_at);
+        true
+    }
 
-        manager
+    /// Sends a `countdown:tick` every second until `starts_at`, then starts the
+    /// race. Stops early once the countdown is called off by a reschedule or a
+    /// cancellation.
+    async fn run_countdown(&self, starts_at: DateTime<Utc>) {
+        for remaining in (1..=countdown_seconds(starts_at, Utc::now())).rev() {
+            let tick_at = starts_at - TimeDelta::seconds(remaining);
+            if let Ok(wait) = (tick_at - Utc::now()).to_std() {
+                tokio::time::sleep(wait).await;
+            }
+            if self.inner.lobby.lock().await.starts_at != Some(starts_at) {
+                return;
+            }
+
+            self.inner
+                .app_state
+                .socket_io
+                .to(self.inner.tournament_id.to_string())
+                .emit(
+                    "countdown:tick",
+                    &CountdownTickPayload {
+                        remaining,
+                        starts_at,
+                    },
+                )
+                .await
+                .inspect_err(|e| error!("Failed to emit countdown:tick: {}", e))
+                .ok();
+        }
+
+        if let Ok(wait) = (starts_at - Utc::now()).to_std() {
+            tokio::time::sleep(wait).await;
+        }
+        if self.inner.lobby.lock().await.starts_at != Some(starts_at) {
+            return;
+        }
+
+        self.clone().execute_tournament_start_logic().await;
+    }
+
+    /// Lets the creator start ahead of `scheduled_for` once every participant is
+    /// ready. Returns when the text will be revealed.
+    async fn start_early(
+        &self,
+        member: &TournamentRoomMember,
+    ) -> Result<DateTime<Utc>, WsFailurePayload> {
+        if member.user_id.as_deref() != Some(self.inner.meta().created_by.as_str()) {
+            return Err(WsFailurePayload::new(
+                3103,
+                "Only the tournament creator can start it early.",
+            ));
+        }
+
+        if self.has_started().await {
+            return Err(WsFailurePayload::new(
+                3102,
+                "Tournament has already started.",
+            ));
+        }
+
+        let starts_at = {
+            let mut lobby_guard = self.inner.lobby.lock().await;
+            if lobby_guard.starts_at.is_some() {
+                return Err(WsFailurePayload::new(
+                    3102,
+                    "Tournament has already started.",
+                ));
+            }
+            if !lobby_guard.all_ready(&self.inner.participants.keys()) {
+                return Err(WsFailurePayload::new(
+                    3104,
+                    "Not every participant is ready.",
+                ));
+            }
+
+            let starts_at = Utc::now() + TimeDelta::seconds(START_COUNTDOWN_SECS);
+            lobby_guard.starts_at = Some(starts_at);
+            starts_at
+        };
+
+        info!(
+            "Tournament {} starts early at {}",
+            self.inner.tournament_id, starts_at
+        );
+
+        let manager = self.clone();
+        tokio::spawn(async move {
+            manager.run_countdown(starts_at).await;
+        });
+
+        Ok(starts_at)
+    }
+
+    /// Marks a participant ready or not ready in the lobby.
+    async fn set_ready(&self, member_id: &str, ready: bool) -> Result<(), WsFailurePayload> {
+        if self.has_started().await {
+            return Err(WsFailurePayload::new(
+                3102,
+                "Tournament has already started.",
+            ));
+        }
+        if !self.inner.participants.contains_key(member_id) {
+            return Err(WsFailurePayload::new(3101, "Your session was not found."));
+        }
+
+        let mut lobby_guard = self.inner.lobby.lock().await;
+        if ready {
+            lobby_guard.ready.insert(member_id.to_owned());
+        } else {
+            lobby_guard.ready.remove(member_id);
+        }
+
+        Ok(())
     }
 
     fn create_update_all_broadcaster(inner: Arc<TournamentManagerInner>) -> Debouncer {
@@ -165,7 +353,8 @@ impl TournamentManager {
                             .get(&inner.tournament_id)
                         {
                             tokio::spawn(async move {
-                                manager.shutdown().await;
+                                let round = manager.inner.bracket.lock().await.round;
+                                manager.end_round(round).await;
                             });
                         } else {
                             error!("Tournament manager not found for {}", inner.tournament_id);
@@ -198,6 +387,10 @@ impl TournamentManager {
 
                     let update_all_payload = UpdateAllPayload {
                         updates: updates_for_all,
+                        teams: inner
+                            .meta()
+                            .team_scoring
+                            .map(|scoring| rank_teams(&all_participants, scoring)),
                     };
 
                     let tournament_room_id = inner.tournament_id.to_string();
@@ -224,51 +417,245 @@ impl TournamentManager {
     }
 
     async fn execute_tournament_start_logic(self) {
+        {
+            let session_state_guard = self.inner.tournament_session_state.lock().await;
+            if session_state_guard.started_at.is_some() || session_state_guard.ended_at.is_some() {
+                return;
+            }
+        }
+
         let participant_count = self.inner.participants.count();
 
         if participant_count > 0 {
-            let text = generate_text(self.inner.tournament_meta.text_options.unwrap_or_default());
+            // Members still waiting for a seat watch the race like spectators.
+            self.inner.waitlist.lock().await.clear();
+
+            self.start_round(1).await;
+        } else {
+            info!(
+                "No participants for tournament {}. Ending immediately.",
+                self.inner.tournament_id
+            );
+            self.shutdown().await;
+            //self.inner.end_tournament().await;
+        }
+    }
+
+    /// Boxed because rounds and their ends schedule each other.
+    fn start_round(&self, round: i32) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(self.run_round(round))
+    }
+
+    /// Runs race `round` with a fresh text. Only starts if the previous round has
+    /// ended and the tournament hasn't.
+    async fn run_round(&self, round: i32) {
+        {
+            let mut bracket_guard = self.inner.bracket.lock().await;
+            if bracket_guard.racing || bracket_guard.round != round - 1 {
+                return;
+            }
+            bracket_guard.round = round;
+            bracket_guard.racing = true;
+        }
+
+        let meta = self.inner.meta();
+        let db = &self.inner.app_state.conn;
+        let options = meta.text_options.unwrap_or_default();
+        let seed = self.inner.text_seed;
+        let generated = generate_text(db, options, race_seed(seed, round, 0)).await;
+        let mut text = generated.text;
+        let mut stretches = vec![TextStretch {
+            round,
+            offset: 0,
+            passage: generated.passage,
+        }];
+        if meta.mode == RaceMode::TimeAttack {
+            loop {
+                let offset = text.chars().count();
+                if offset >= TIME_ATTACK_LOOKAHEAD {
+                    break;
+                }
+                let chunk = text_chunk(db, &meta, seed, round, offset).await;
+                text.push_str(&chunk.text);
+                stretches.push(TextStretch {
+                    round,
+                    offset,
+                    passage: chunk.passage,
+                });
+            }
+        }
+        let current_time = Utc::now();
+        let scheduled_end = current_time + TimeDelta::seconds(meta.race_duration_secs.into());
+        {
             let mut session_state_guard = self.inner.tournament_session_state.lock().await;
-            let current_time = Utc::now();
-            let scheduled_end = current_time + TimeDelta::minutes(10);
+            if session_state_guard.ended_at.is_some() {
+                return;
+            }
             session_state_guard.scheduled_end = Some(scheduled_end);
-            *self.inner.typing_text.write().unwrap() = Arc::new(text);
-            session_state_guard.started_at = Some(current_time);
-            std::mem::drop(session_state_guard);
+            session_state_guard.started_at.get_or_insert(current_time);
+            *self.inner.typing_text.write().unwrap() = Arc::new(text.clone());
+            self.inner.text_stretches.lock().unwrap().extend(stretches);
+        }
 
-            for socket in self
-                .inner
-                .app_state
-                .socket_io
-                .within(self.inner.tournament_id.to_string())
-                .sockets()
-            {
+        if round > 1 {
+            for member_id in self.inner.participants.keys() {
+                let session = self.inner.participants.update_data(&member_id, |session| {
+                    *session = TypingSessionSchema::new(
+                        session.member.clone(),
+                        self.inner.tournament_id.to_string(),
+                    );
+                    session.clone()
+                });
+                if let Some(session) = session {
+                    self.inner
+                        .app_state
+                        .typing_session_registry
+                        .set_session(&member_id, session);
+                }
+                // Replays keep each member's last race only.
+                self.inner.replays.delete_data(&member_id);
+            }
+        }
+
+        // Registered anew every round so each race gets a fresh inactivity timer.
+        for socket in self
+            .inner
+            .app_state
+            .socket_io
+            .within(self.inner.tournament_id.to_string())
+            .sockets()
+        {
+            let seated = socket
+                .extensions
+                .get::<Arc<TournamentRoomMember>>()
+                .is_some_and(|member| self.inner.participants.contains_key(&member.id));
+
+            if seated {
                 self.register_type_listeners(socket, false);
             }
+        }
 
+        if round == 1 {
             self.inner.broadcast_update_data(true).await;
-            let manager = self.clone();
+        }
 
-            crate::scheduler::schedule_new_task(
-                async move {
-                    manager.shutdown().await;
-                    // manager.inner.end_tournament().await;
-                    // manager.update_all_broadcaster.shutdown().await;
-                },
-                scheduled_end,
-            )
-            .ok();
-        } else {
+        if meta.rounds > 1 {
             info!(
-                "No participants for tournament {}. Ending immediately.",
-                self.inner.tournament_id
+                "Starting round {} of {} for tournament {}",
+                round, meta.rounds, self.inner.tournament_id
             );
-            self.shutdown().await;
-            //self.inner.end_tournament().await;
+
+            let round_started_payload = RoundStartedPayload {
+                round,
+                rounds: meta.rounds,
+                started_at: current_time,
+                scheduled_end,
+                text,
+            };
+
+            self.inner
+                .app_state
+                .socket_io
+                .to(self.inner.tournament_id.to_string())
+                .emit("round:started", &round_started_payload)
+                .await
+                .inspect_err(|e| error!("Failed to emit round:started: {}", e))
+                .ok();
         }
+
+        let manager = self.clone();
+        crate::scheduler::schedule_new_task(
+            async move {
+                manager.end_round(round).await;
+            },
+            scheduled_end,
+        )
+        .ok();
     }
 
-    fn map_session_to_api_participant_data(session: &TypingSessionSchema) -> ParticipantData {
+    /// Ends race `round` if it is still running. Between rounds the bottom of the
+    /// ranking is eliminated and the next round is scheduled; after the last round,
+    /// or once a single member is left, the tournament shuts down.
+    async fn end_round(&self, round: i32) {
+        {
+            let mut bracket_guard = self.inner.bracket.lock().await;
+            if !bracket_guard.racing || bracket_guard.round != round {
+                return;
+            }
+            bracket_guard.racing = false;
+        }
+
+        let meta = self.inner.meta();
+        if meta.mode == RaceMode::TimeAttack {
+            // Everyone still typing when the clock runs out completes the race.
+            let ended_at = Utc::now();
+            for member_id in self.inner.participants.keys() {
+                self.inner.participants.update_data(&member_id, |session| {
+                    if session.started_at.is_some() && session.ended_at.is_none() {
+                        session.ended_at = Some(ended_at);
+                    }
+                });
+            }
+        }
+        let ranked = self.inner.ranked_participants();
+
+        let (advancing, eliminated) = if round >= meta.rounds {
+            (ranked, Vec::new())
+        } else {
+            split_round(ranked, meta.eliminations_per_round as usize)
+        };
+
+        for session in &eliminated {
+            self.inner.participants.delete_data(&session.member.id);
+            self.inner
+                .app_state
+                .typing_session_registry
+                .delete_session(&session.member.id);
+        }
+
+        let last_round = round >= meta.rounds || advancing.len() <= 1;
+        let next_round_at = (!last_round).then(|| Utc::now() + ROUND_COUNTDOWN);
+
+        if meta.rounds > 1 {
+            let round_ended_payload = RoundEndedPayload {
+                round,
+                advancing: advancing.iter().map(|s| s.member.id.clone()).collect(),
+                eliminated: eliminated.iter().map(|s| s.member.id.clone()).collect(),
+                next_round_at,
+            };
+
+            self.inner
+                .app_state
+                .socket_io
+                .to(self.inner.tournament_id.to_string())
+                .emit("round:ended", &round_ended_payload)
+                .await
+                .inspect_err(|e| error!("Failed to emit round:ended: {}", e))
+                .ok();
+        }
+
+        if !eliminated.is_empty() {
+            self.inner.bracket.lock().await.eliminated.push(eliminated);
+        }
+
+        match next_round_at {
+            Some(next_round_at) => {
+                let manager = self.clone();
+                crate::scheduler::schedule_new_task(
+                    async move {
+                        manager.start_round(round + 1).await;
+                    },
+                    next_round_at,
+                )
+                .ok();
+            }
+            None => self.shutdown().await,
+        }
+    }
+
+    pub(crate) fn map_session_to_api_participant_data(
+        session: &TypingSessionSchema,
+    ) -> ParticipantData {
         ParticipantData {
             member: session.member.clone(),
             current_position: session.current_position,
@@ -281,13 +668,20 @@ impl TournamentManager {
         }
     }
 
-    pub async fn connect(self, socket: SocketRef, spectator: bool, noauth: String) -> Result<()> {
+    pub async fn connect(
+        self,
+        socket: SocketRef,
+        spectator: bool,
+        waitlist: bool,
+        noauth: String,
+    ) -> Result<()> {
         let member_schema = socket
             .extensions
             .get::<Arc<TournamentRoomMember>>()
             .unwrap();
 
         let now = Utc::now();
+        let mut waitlist_position = None;
 
         if !spectator && !self.inner.participants.contains_key(&member_schema.id) {
             let (started_at, ended_at) = {
@@ -295,11 +689,17 @@ impl TournamentManager {
                 (session_state_guard.started_at, session_state_guard.ended_at)
             };
 
-            let scheduled_for = self.inner.tournament_meta.scheduled_for;
-            let join_deadline =
-                TimeDelta::from_std(JOIN_DEADLINE).unwrap_or_else(|_| TimeDelta::seconds(15));
+            let counting_down = self.inner.lobby.lock().await.starts_at.is_some();
 
-            if ended_at.is_some() || started_at.is_some() || (scheduled_for - now < join_deadline) {
+            let meta = self.inner.meta();
+            let scheduled_for = meta.scheduled_for;
+            let join_deadline = TimeDelta::seconds(meta.join_cutoff_secs.into());
+
+            if ended_at.is_some()
+                || started_at.is_some()
+                || counting_down
+                || (scheduled_for - now < join_deadline)
+            {
                 error!(member_id = %member_schema.id, "Tournament no longer accepting participants.");
                 let failure_payload =
                     WsFailurePayload::new(1004, "Tournament no longer accepting participants.");
@@ -311,6 +711,57 @@ impl TournamentManager {
                     "Tournament no longer accepting participants."
                 ));
             }
+
+            if meta.team_scoring.is_some() && member_schema.team.is_none() {
+                warn!(member_id = %member_schema.id, "No team picked for a team tournament.");
+                let failure_payload = WsFailurePayload::new(
+                    1008,
+                    &format!(
+                        "Pick a team of 1 to {MAX_TEAM_NAME_LENGTH} characters to join this tournament."
+                    ),
+                );
+
+                if socket.emit("join:failure", &failure_payload).is_err() {
+                    warn!("Failed to send join:failure to member {}", member_schema.id);
+                }
+                return Err(anyhow::anyhow!("No team picked for a team tournament."));
+            }
+
+            if let Some(max_participants) = meta.max_participants {
+                // Held until the seat is taken so concurrent joins can't overfill.
+                let mut waitlist_guard = self.inner.waitlist.lock().await;
+
+                if self.inner.participants.count() < max_participants as usize {
+                    self.inner
+                        .participants
+                        .get_or_insert(&member_schema.id, || {
+                            TypingSessionSchema::new(
+                                (*member_schema).clone(),
+                                self.inner.tournament_id.to_string(),
+                            )
+                        });
+                } else if waitlist {
+                    let position = match waitlist_guard
+                        .iter()
+                        .position(|waiting| waiting.id == member_schema.id)
+                    {
+                        Some(index) => index + 1,
+                        None => {
+                            waitlist_guard.push_back(member_schema.clone());
+                            waitlist_guard.len()
+                        }
+                    };
+                    waitlist_position = Some(position);
+                } else {
+                    warn!(member_id = %member_schema.id, "Tournament is full.");
+                    let failure_payload = WsFailurePayload::new(1007, "Tournament is full.");
+
+                    if socket.emit("join:failure", &failure_payload).is_err() {
+                        warn!("Failed to send join:failure to member {}", member_schema.id);
+                    }
+                    return Err(anyhow::anyhow!("Tournament is full."));
+                }
+            }
         }
 
         info!(
@@ -323,7 +774,7 @@ impl TournamentManager {
         let current_tournament_data;
         {
             let t_session_state_guard = self.inner.tournament_session_state.lock().await;
-            let t_meta = &self.inner.tournament_meta;
+            let t_meta = self.inner.meta();
             current_tournament_data = TournamentData {
                 id: t_meta.id.clone(),
                 title: t_meta.title.clone(),
@@ -339,10 +790,15 @@ impl TournamentManager {
                     None
                 },
                 scheduled_end: t_session_state_guard.scheduled_end,
+                max_participants: t_meta.max_participants,
+                team_scoring: t_meta.team_scoring,
+                mode: t_meta.mode,
+                rounds: t_meta.rounds,
+                round: self.inner.bracket.lock().await.round,
             };
         }
 
-        if !spectator {
+        if !spectator && waitlist_position.is_none() {
             // Add or get participant session
             let participant_session =
                 self.inner
@@ -392,6 +848,16 @@ impl TournamentManager {
             member: (*member_schema).clone(),
             participants: all_participants_api_data,
             noauth,
+            waitlist_position,
+            ready: self
+                .inner
+                .lobby
+                .lock()
+                .await
+                .ready
+                .iter()
+                .cloned()
+                .collect(),
         };
 
         // Emit join:success to the current socket
@@ -419,20 +885,9 @@ impl TournamentManager {
 
         let rid = progress.rid;
 
-        let original = self.inner.typing_text.read().unwrap().clone();
-
-        let update_result = self.inner.participants.update_data(
-            &member.id,
-            // This closure contains all the mutation logic.
-            // It receives `&mut TypingSessionSchema`.
-            move |session| {
-                self.algorithm
-                    .handle_progress(session, progress, original.as_bytes())
-            },
-        );
-
-        match update_result {
+        match self.apply_progress(&member.id, progress) {
             Some(Ok(changes)) => {
+                self.record_replay_frame(&member.id, &[], &changes);
                 let update_me_payload = UpdateMePayload {
                     updates: changes,
                     rid,
@@ -441,6 +896,9 @@ impl TournamentManager {
                     warn!("Failed to send update:me to {}: {}", member.id, e);
                 }
                 self.update_all_broadcaster.trigger();
+                if let Some(position) = update_me_payload.updates.current_position {
+                    self.extend_text(position);
+                }
             }
 
             Some(Err(failure_payload)) => {
@@ -456,21 +914,57 @@ impl TournamentManager {
         }
     }
 
+    fn apply_progress(
+        &self,
+        member_id: &str,
+        progress: ProgressEventPayload,
+    ) -> Option<Result<PartialParticipantData, WsFailurePayload>> {
+        let original = self
+            .inner
+            .typing_text
+            .read()
+            .unwrap()
+            .chars()
+            .collect::<Vec<_>>();
+
+        self.inner.participants.update_data(member_id, |session| {
+            self.inner
+                .key_stats
+                .upsert_data(member_id, KeyStats::default, |key_stats| {
+                    self.inner
+                        .algorithm()
+                        .handle_progress(session, progress, &original, key_stats)
+                })
+        })
+    }
+
     async fn handle_typing(self, socket: SocketRef, typed_chars: Vec<char>, rid: i32) {
         let member = socket
             .extensions
             .get::<Arc<TournamentRoomMember>>()
             .unwrap();
         let cache = self.inner.participants.clone();
-        let original = self.inner.typing_text.read().unwrap().clone();
+        let original = self
+            .inner
+            .typing_text
+            .read()
+            .unwrap()
+            .chars()
+            .collect::<Vec<_>>();
 
-        let update_result = cache.update_data(&member.id, move |session| {
-            self.algorithm
-                .handle_type(session, &typed_chars, original.as_bytes())
+        let update_result = cache.update_data(&member.id, |session| {
+            self.inner
+                .key_stats
+                .upsert_data(&member.id, KeyStats::default, |key_stats| {
+                    self.inner
+                        .algorithm()
+                        .handle_type(session, &typed_chars, &original, key_stats)
+                })
         });
 
         match update_result {
             Some(Ok(changes)) => {
+                self.record_replay_frame(&member.id, &typed_chars, &changes);
                 let update_me_payload = UpdateMePayload {
                     updates: changes,
                     rid,
@@ -479,6 +973,9 @@ impl TournamentManager {
                     warn!("Failed to send update:me to {}: {}", member.id, e);
                 }
                 self.update_all_broadcaster.trigger();
+                if let Some(position) = update_me_payload.updates.current_position {
+                    self.extend_text(position);
+                }
             }
 
             Some(Err(failure_payload)) => {
@@ -494,6 +991,232 @@ impl TournamentManager {
         }
     }
 
+    /// Appends text to a time-attack race once `position` gets close to the end of
+    /// it, and streams the new chunk to the room. Chunks may come from the passage
+    /// corpus, so they are generated in the background rather than on the keystroke.
+    fn extend_text(&self, position: usize) {
+        let meta = self.inner.meta();
+        if meta.mode != RaceMode::TimeAttack {
+            return;
+        }
+
+        let offset = self.inner.typing_text.read().unwrap().chars().count();
+        if offset >= position + TIME_ATTACK_LOOKAHEAD {
+            return;
+        }
+
+        let manager = self.clone();
+        tokio::spawn(async move {
+            let db = &manager.inner.app_state.conn;
+            let round = manager.inner.bracket.lock().await.round;
+            let mut appended = String::new();
+            let mut stretches = Vec::new();
+            loop {
+                let chunk_offset = offset + appended.chars().count();
+                if chunk_offset >= position + TIME_ATTACK_LOOKAHEAD {
+                    break;
+                }
+                let chunk =
+                    text_chunk(db, &meta, manager.inner.text_seed, round, chunk_offset).await;
+                appended.push_str(&chunk.text);
+                stretches.push(TextStretch {
+                    round,
+                    offset: chunk_offset,
+                    passage: chunk.passage,
+                });
+            }
+
+            let chunk = {
+                let mut text_guard = manager.inner.typing_text.write().unwrap();
+                // Another keystroke extended the text while this chunk was generated.
+                if text_guard.chars().count() != offset {
+                    return;
+                }
+                *text_guard = Arc::new(format!("{}{}", text_guard, appended));
+                manager
+                    .inner
+                    .text_stretches
+                    .lock()
+                    .unwrap()
+                    .extend(stretches);
+                TextChunk {
+                    offset,
+                    text: appended,
+                }
+            };
+
+            let update_data_payload = UpdateDataPayload {
+                updates: PartialTournamentData {
+                    title: None,
+                    scheduled_for: None,
+                    description: None,
+                    started_at: None,
+                    ended_at: None,
+                    text: None,
+                    text_chunk: Some(chunk),
+                },
+            };
+
+            manager
+                .inner
+                .app_state
+                .socket_io
+                .to(manager.inner.tournament_id.to_string())
+                .emit("update:data", &update_data_payload)
+                .await
+                .inspect_err(|e| error!("Failed to emit update:data for text chunk: {}", e))
+                .ok();
+        });
+    }
+
+    fn member_sockets(&self, member_id: &str) -> Vec<SocketRef> {
+        self.inner
+            .app_state
+            .socket_io
+            .within(self.inner.tournament_id.to_string())
+            .sockets()
+            .into_iter()
+            .filter(|socket| {
+                socket
+                    .extensions
+                    .get::<Arc<TournamentRoomMember>>()
+                    .is_some_and(|member| member.id == member_id)
+            })
+            .collect()
+    }
+
+    fn emit_to_member<T: serde::Serialize + ?Sized>(&self, member_id: &str, event: &str, data: &T) {
+        for socket in self.member_sockets(member_id) {
+            if let Err(e) = socket.emit(event, data) {
+                warn!("Failed to send {} to {}: {}", event, member_id, e);
+            }
+        }
+    }
+
+    /// Seats waitlisted members while there is room, then tells the ones still
+    /// waiting where they stand. Does nothing once the race has started.
+    async fn promote_waitlisted(&self) {
+        if self.has_started().await {
+            return;
+        }
+
+        let max_participants = self
+            .inner
+            .meta()
+            .max_participants
+            .map_or(usize::MAX, |max_participants| max_participants as usize);
+
+        let (promoted, waiting) = {
+            let mut waitlist_guard = self.inner.waitlist.lock().await;
+            let mut promoted = Vec::new();
+
+            while self.inner.participants.count() < max_participants {
+                let Some(member) = waitlist_guard.pop_front() else {
+                    break;
+                };
+
+                let session = self.inner.participants.get_or_insert(&member.id, || {
+                    TypingSessionSchema::new(
+                        (*member).clone(),
+                        self.inner.tournament_id.to_string(),
+                    )
+                });
+                self.inner
+                    .app_state
+                    .typing_session_registry
+                    .set_session(&member.id, session.clone());
+                promoted.push(session);
+            }
+
+            let waiting = (!promoted.is_empty()).then(|| {
+                waitlist_guard
+                    .iter()
+                    .map(|member| member.id.clone())
+                    .collect::<Vec<_>>()
+            });
+
+            (promoted, waiting)
+        };
+
+        for session in promoted {
+            info!(
+                "Promoted member {} from the waitlist of tournament {}",
+                session.member.id, self.inner.tournament_id
+            );
+
+            let participant = Self::map_session_to_api_participant_data(&session);
+            let member_sockets = self.member_sockets(&session.member.id);
+
+            let mut others = self
+                .inner
+                .app_state
+                .socket_io
+                .to(self.inner.tournament_id.to_string());
+            for socket in &member_sockets {
+                others = others.except(socket.id);
+            }
+
+            if let Err(e) = others
+                .emit(
+                    "participant:joined",
+                    &ParticipantJoinedPayload {
+                        participant: participant.clone(),
+                    },
+                )
+                .await
+            {
+                warn!("Failed to broadcast participant:joined: {}", e);
+            }
+
+            for socket in member_sockets {
+                if let Err(e) = socket.emit(
+                    "waitlist:promoted",
+                    &WaitlistPromotedPayload {
+                        participant: participant.clone(),
+                    },
+                ) {
+                    warn!(
+                        "Failed to send waitlist:promoted to {}: {}",
+                        session.member.id, e
+                    );
+                }
+            }
+        }
+
+        self.notify_waitlist_positions(waiting.unwrap_or_default());
+    }
+
+    fn notify_waitlist_positions(&self, waiting: Vec<String>) {
+        for (index, member_id) in waiting.iter().enumerate() {
+            self.emit_to_member(
+                member_id,
+                "waitlist:position",
+                &WaitlistPositionPayload {
+                    position: index + 1,
+                },
+            );
+        }
+    }
+
+    fn record_replay_frame(
+        &self,
+        member_id: &str,
+        keys: &[char],
+        changes: &PartialParticipantData,
+    ) {
+        let (Some(current_position), Some(correct_position)) =
+            (changes.current_position, changes.correct_position)
+        else {
+            return;
+        };
+
+        self.inner
+            .replays
+            .upsert_data(member_id, ReplayLog::default, |log| {
+                log.record(Utc::now(), keys, current_position, correct_position)
+            });
+    }
+
     fn register_type_listeners(&self, socket: SocketRef, secure: bool) {
         let member = socket
             .extensions
@@ -504,7 +1227,8 @@ impl TournamentManager {
             let debounce_duration = DEBOUNCE_DURATION;
             let max_process_wait = MAX_PROCESS_WAIT;
             let max_process_stack_size = MAX_PROCESS_STACK_SIZE;
-            let cleanup_wait_duration = INACTIVITY_TIMEOUT_DURATION;
+            let cleanup_wait_duration =
+                Duration::from_secs(self.inner.meta().inactivity_timeout_secs as u64);
             let manager_clone = self.clone();
             let timeout_monitor = {
                 let socket = socket.clone();
@@ -679,6 +1403,64 @@ impl TournamentManager {
             });
         }
 
+        if !spectator {
+            socket.on("ready", {
+                let manager_clone_ready = self.clone();
+                move |s: SocketRef, Data::<ReadyEventPayload>(ReadyEventPayload { ready })| {
+                    let mc_ready = manager_clone_ready.clone();
+                    let member_ready = s.extensions.get::<Arc<TournamentRoomMember>>().unwrap();
+                    async move {
+                        if let Err(failure_payload) =
+                            mc_ready.set_ready(&member_ready.id, ready).await
+                        {
+                            s.emit("ready:failure", &failure_payload).ok();
+                            return;
+                        }
+
+                        let participant_ready_payload = ParticipantReadyPayload {
+                            member_id: member_ready.id.clone(),
+                            ready,
+                        };
+                        if s.emit("ready:success", &participant_ready_payload).is_err() {
+                            warn!("Failed to send ready:success to member {}", member_ready.id);
+                        }
+                        mc_ready
+                            .inner
+                            .app_state
+                            .socket_io
+                            .to(mc_ready.inner.tournament_id.to_string())
+                            .except(s.id)
+                            .emit("participant:ready", &participant_ready_payload)
+                            .await
+                            .inspect_err(|e| warn!("Failed to broadcast participant:ready: {}", e))
+                            .ok();
+                    }
+                }
+            });
+        }
+
+        socket.on("start", {
+            let manager_clone_start = self.clone();
+            move |s: SocketRef| {
+                let mc_start = manager_clone_start.clone();
+                let member_start = s.extensions.get::<Arc<TournamentRoomMember>>().unwrap();
+                async move {
+                    match mc_start.start_early(&member_start).await {
+                        Ok(starts_at) => {
+                            if s.emit("start:success", &StartSuccessPayload { starts_at })
+                                .is_err()
+                            {
+                                warn!("Failed to send start:success to member {}", member_start.id);
+                            }
+                        }
+                        Err(failure_payload) => {
+                            s.emit("start:failure", &failure_payload).ok();
+                        }
+                    }
+                }
+            }
+        });
+
         socket.on("all", {
             let manager_clone_all = self.clone();
             move |s: SocketRef| {
@@ -712,7 +1494,7 @@ impl TournamentManager {
                     {
                         let t_session_state_guard =
                             mc_data.inner.tournament_session_state.lock().await;
-                        let t_meta = &mc_data.inner.tournament_meta;
+                        let t_meta = mc_data.inner.meta();
                         current_tournament_data = TournamentData {
                             id: t_meta.id.clone(),
                             title: t_meta.title.clone(),
@@ -728,6 +1510,11 @@ impl TournamentManager {
                                 None
                             },
                             scheduled_end: t_session_state_guard.scheduled_end,
+                            max_participants: t_meta.max_participants,
+                            team_scoring: t_meta.team_scoring,
+                            mode: t_meta.mode,
+                            rounds: t_meta.rounds,
+                            round: mc_data.inner.bracket.lock().await.round,
                         };
                     }
                     if socket_data
@@ -753,6 +1540,9 @@ impl TournamentManager {
         );
 
         if self.inner.participants.delete_data(member_id_str).is_some() {
+            self.inner.lobby.lock().await.ready.remove(member_id_str);
+            self.inner.replays.delete_data(member_id_str);
+            self.inner.key_stats.delete_data(member_id_str);
             self.inner
                 .app_state
                 .typing_session_registry
@@ -762,6 +1552,7 @@ impl TournamentManager {
 
             let participant_left_payload = ParticipantLeftPayload {
                 member_id: member_id_str.to_string(),
+                reason: LeaveReason::Left,
             };
 
             let io_clone = self.inner.app_state.socket_io.clone();
@@ -793,6 +1584,12 @@ impl TournamentManager {
                     //self.inner.end_tournament().await;
                 }
             }
+
+            self.promote_waitlisted().await;
+            Ok(())
+        } else if let Some(waiting) = self.leave_waitlist(member_id_str).await {
+            socket.leave(self.inner.tournament_id.to_string());
+            self.notify_waitlist_positions(waiting);
             Ok(())
         } else {
             warn!(
@@ -804,13 +1601,99 @@ impl TournamentManager {
         }
     }
 
+    /// Removes a member the creator kicked or banned: their session is dropped,
+    /// the room is told why and their sockets are disconnected. Returns whether
+    /// the member was seated, waitlisted or connected at all.
+    pub async fn remove_member(&self, member_id: &str, reason: LeaveReason) -> bool {
+        let sockets = self.member_sockets(member_id);
+
+        let seated = self.inner.participants.delete_data(member_id).is_some();
+        if seated {
+            self.inner.lobby.lock().await.ready.remove(member_id);
+            self.inner.replays.delete_data(member_id);
+            self.inner.key_stats.delete_data(member_id);
+            self.inner
+                .app_state
+                .typing_session_registry
+                .delete_session(member_id);
+        }
+        let waiting = self.leave_waitlist(member_id).await;
+
+        if !seated && waiting.is_none() && sockets.is_empty() {
+            return false;
+        }
+
+        info!(
+            "Removing member {} from tournament {} ({:?})",
+            member_id, self.inner.tournament_id, reason
+        );
+
+        let participant_left_payload = ParticipantLeftPayload {
+            member_id: member_id.to_string(),
+            reason,
+        };
+
+        if let Err(e) = self
+            .inner
+            .app_state
+            .socket_io
+            .to(self.inner.tournament_id.to_string())
+            .emit("participant:left", &participant_left_payload)
+            .await
+        {
+            warn!(
+                "Failed to broadcast participant:left for {}: {}",
+                member_id, e
+            );
+        }
+
+        for socket in sockets {
+            socket.leave(self.inner.tournament_id.to_string());
+            socket.disconnect().ok();
+        }
+
+        if seated {
+            self.update_all_broadcaster.trigger();
+
+            if self.inner.participants.count() == 0 && self.has_started().await {
+                self.shutdown().await;
+            }
+
+            self.promote_waitlisted().await;
+        } else if let Some(waiting) = waiting {
+            self.notify_waitlist_positions(waiting);
+        }
+
+        true
+    }
+
+    /// Takes the member off the waitlist. Returns the members still waiting, or
+    /// `None` if the member wasn't waitlisted.
+    async fn leave_waitlist(&self, member_id: &str) -> Option<Vec<String>> {
+        let mut waitlist_guard = self.inner.waitlist.lock().await;
+        let index = waitlist_guard
+            .iter()
+            .position(|member| member.id == member_id)?;
+        waitlist_guard.remove(index);
+
+        Some(
+            waitlist_guard
+                .iter()
+                .map(|member| member.id.clone())
+                .collect(),
+        )
+    }
+
     pub async fn handle_timeout(self, socket: SocketRef) {
         let member = socket
             .extensions
             .get::<Arc<TournamentRoomMember>>()
             .unwrap();
         self.inner.participants.update_data(&member.id, |m| {
-            m.ended_at = Some(Utc::now());
+            if m.ended_at.is_none() {
+                m.ended_at = Some(Utc::now());
+                m.timed_out = true;
+            }
         });
         self.update_all_broadcaster.trigger();
     }
@@ -818,6 +1701,14 @@ impl TournamentManager {
     pub async fn live_data(&self, member_id: &str) -> TournamentLiveData {
         let participant_count = self.inner.participants.count();
         let participating = self.inner.participants.contains_key(&member_id);
+        let waitlist_position = self
+            .inner
+            .waitlist
+            .lock()
+            .await
+            .iter()
+            .position(|member| member.id == member_id)
+            .map(|index| index + 1);
 
         let (started_at, ended_at) = {
             let session_state_guard = self.inner.tournament_session_state.lock().await;
@@ -827,19 +1718,138 @@ impl TournamentManager {
         TournamentLiveData {
             participant_count,
             participating,
+            waitlist_position,
             started_at,
             ended_at,
         }
     }
 
-    pub async fn shutdown(&self) {
-        let now = Utc::now();
+    pub async fn has_started(&self) -> bool {
+        let session_state_guard = self.inner.tournament_session_state.lock().await;
+        session_state_guard.started_at.is_some() || session_state_guard.ended_at.is_some()
+    }
+
+    /// Stores an update through `write` and applies the stored tournament, unless the
+    /// race has already started, in which case nothing is written and `None` returned.
+    /// The race can't start while `write` runs, so the stored and the live tournament
+    /// always agree.
+    pub async fn apply_update(
+        &self,
+        write: impl Future<Output = Result<TournamentSchema, DbErr>>,
+    ) -> Result<Option<TournamentSchema>, DbErr> {
+        let (previous, tournament) = {
+            let mut session_state_guard = self.inner.tournament_session_state.lock().await;
+            if session_state_guard.started_at.is_some() || session_state_guard.ended_at.is_some() {
+                return Ok(None);
+            }
+            let previous = self.inner.meta();
+            let tournament = write.await?;
+            session_state_guard.scheduled_for = tournament.scheduled_for;
+            *self.inner.tournament_meta.write().unwrap() = Arc::new(tournament.clone());
+            (previous, tournament)
+        };
+
+        let rescheduled = previous.scheduled_for != tournament.scheduled_for;
+        if rescheduled {
+            info!(
+                "Rescheduling tournament {} to {}",
+                self.inner.tournament_id, tournament.scheduled_for
+            );
+            // Calls off a countdown that is already running.
+            self.inner.lobby.lock().await.starts_at = None;
+            self.schedule_start(tournament.scheduled_for);
+        }
+
+        if previous.max_participants != tournament.max_participants {
+            self.promote_waitlisted().await;
+        }
+
+        let updates = PartialTournamentData {
+            title: (previous.title != tournament.title).then(|| tournament.title.clone()),
+            scheduled_for: rescheduled.then_some(tournament.scheduled_for),
+            description: (previous.description != tournament.description)
+                .then(|| tournament.description.clone()),
+            started_at: None,
+            ended_at: None,
+            text: None,
+            text_chunk: None,
+        };
+
+        if updates.title.is_none()
+            && updates.scheduled_for.is_none()
+            && updates.description.is_none()
+        {
+            return Ok(Some(tournament));
+        }
+
         self.inner
-            .tournament_session_state
-            .lock()
+            .app_state
+            .socket_io
+            .to(self.inner.tournament_id.to_string())
+            .emit("update:data", &UpdateDataPayload { updates })
             .await
-            .ended_at
-            .get_or_insert(now);
+            .inspect_err(|e| error!("Failed to emit update:data for tournament update: {}", e))
+            .ok();
+
+        Ok(Some(tournament))
+    }
+
+    pub async fn cancel(&self) -> Result<()> {
+        {
+            let mut session_state_guard = self.inner.tournament_session_state.lock().await;
+            if session_state_guard.started_at.is_some() || session_state_guard.ended_at.is_some() {
+                return Err(anyhow::anyhow!("Tournament has already started."));
+            }
+            session_state_guard.ended_at = Some(Utc::now());
+        }
+        self.inner.lobby.lock().await.starts_at = None;
+
+        info!("Cancelling tournament {}", &*self.inner.tournament_id);
+
+        self.inner.broadcast_update_data(false).await;
+        self.update_all_broadcaster.shutdown().await;
+
+        for socket in self
+            .inner
+            .app_state
+            .socket_io
+            .within(self.inner.tournament_id.to_string())
+            .sockets()
+        {
+            socket.disconnect().ok();
+        }
+
+        for member_id in self.inner.participants.keys() {
+            self.inner.participants.delete_data(&member_id);
+            self.inner
+                .app_state
+                .typing_session_registry
+                .delete_session(&member_id);
+        }
+        self.inner.replays.clear();
+        self.inner.key_stats.clear();
+        self.inner.waitlist.lock().await.clear();
+
+        self.inner
+            .app_state
+            .tournament_registry
+            .evict(&self.inner.tournament_id);
+
+        Ok(())
+    }
+
+    pub async fn shutdown(&self) {
+        let now = Utc::now();
+        let already_ended = {
+            let mut session_state_guard = self.inner.tournament_session_state.lock().await;
+            let already_ended = session_state_guard.ended_at.is_some();
+            session_state_guard.ended_at.get_or_insert(now);
+            already_ended
+        };
+
+        if already_ended {
+            return;
+        }
 
         info!(
             "Shutting down manager for tournament {}",
@@ -859,6 +1869,69 @@ impl TournamentManager {
             error!("Failed to persist final tournament state: {}", e);
         }
 
+        let stretches = std::mem::take(&mut *self.inner.text_stretches.lock().unwrap());
+        if let Err(e) = record_tournament_texts(
+            &self.inner.app_state.conn,
+            &self.inner.tournament_id,
+            stretches,
+        )
+        .await
+        {
+            error!("Failed to persist tournament texts: {}", e);
+        }
+
+        let eliminated = std::mem::take(&mut self.inner.bracket.lock().await.eliminated);
+        let standings = bracket_standings(self.inner.ranked_participants(), eliminated);
+        let teams = self
+            .inner
+            .meta()
+            .team_scoring
+            .map(|scoring| rank_teams(&standings, scoring))
+            .unwrap_or_default();
+        let replays = standings
+            .iter()
+            .filter_map(|session| {
+                let log = self.inner.replays.delete_data(&session.member.id)?;
+                Some((session.member.clone(), log))
+            })
+            .collect();
+        let key_stats = standings
+            .iter()
+            .filter_map(|session| {
+                let stats = self.inner.key_stats.delete_data(&session.member.id)?;
+                Some((session.member.user_id.clone()?, stats))
+            })
+            .collect();
+        match record_final_results(
+            &self.inner.app_state.conn,
+            &self.inner.tournament_id,
+            &standings,
+            &teams,
+            replays,
+            key_stats,
+            now,
+        )
+        .await
+        {
+            Ok(recorded) => {
+                info!(
+                    "Recorded {} typing history entries for tournament {}",
+                    recorded.history_entries, self.inner.tournament_id
+                );
+                if recorded.history_entries > 0 {
+                    self.inner.app_state.leaderboard_cache.invalidate();
+                }
+                for (member_id, achievements) in recorded.unlocked {
+                    self.emit_to_member(
+                        &member_id,
+                        "achievement:unlocked",
+                        &AchievementUnlockedPayload { achievements },
+                    );
+                }
+            }
+            Err(e) => error!("Failed to persist final standings: {}", e),
+        }
+
         self.inner.broadcast_update_data(false).await;
 
         self.update_all_broadcaster.shutdown().await;
@@ -894,3 +1967,139 @@ impl TournamentManager {
         crate::scheduler::schedule_new_task(evict_task, evict_on).ok();
     }
 }
+
+/// The stretch of a time-attack text starting `offset` characters in, led by the
+/// space that joins it on.
+async fn text_chunk(
+    db: &DbConn,
+    meta: &TournamentSchema,
+    text_seed: i64,
+    round: i32,
+    offset: usize,
+) -> GeneratedText {
+    let options = meta.text_options.unwrap_or_default();
+    let seed = race_seed(text_seed, round, offset);
+    let chunk = generate_text(db, options, seed).await;
+    GeneratedText {
+        text: format!(" {}", chunk.text),
+        ..chunk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use models::{
+        domains::key_stats,
+        params::{tournament::CreateTournamentParams, user::CreateUserParams},
+        schemas::{
+            tournament::TournamentSchema,
+            typing::TypingSessionSchema,
+            user::{TournamentRoomMember, UserSchema},
+        },
+    };
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, TryIntoModel};
+    use socketioxide::SocketIo;
+    use utils::testing::setup_test_db;
+
+    use super::*;
+    use crate::{
+        cache::{LeaderboardCache, TournamentRegistry, TypingSessionRegistry},
+        config::Config,
+        persistence::{
+            leaderboards::LEADERBOARD_CACHE_TTL, tournaments::create_tournament, users::create_user,
+        },
+    };
+
+    fn progress(
+        correct_position: usize,
+        current_position: usize,
+        total_keystrokes: i32,
+    ) -> ProgressEventPayload {
+        ProgressEventPayload {
+            correct_position,
+            current_position,
+            total_keystrokes,
+            rid: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn progress_updates_feed_key_stats() {
+        let (_, socket_io) = SocketIo::new_layer();
+        let app_state = AppState {
+            conn: setup_test_db("sqlite::memory:").await.unwrap(),
+            config: Config::for_tests(),
+            tournament_registry: TournamentRegistry::new(),
+            typing_session_registry: TypingSessionRegistry::new(),
+            leaderboard_cache: LeaderboardCache::new(LEADERBOARD_CACHE_TTL),
+            socket_io,
+        };
+
+        let user = UserSchema::from(
+            create_user(
+                &app_state,
+                CreateUserParams {
+                    email: "racer@example.com".to_string(),
+                    password: "password".to_string(),
+                },
+            )
+            .await
+            .unwrap()
+            .try_into_model()
+            .unwrap(),
+        );
+        let tournament = create_tournament(
+            &app_state.conn,
+            CreateTournamentParams {
+                title: "key stats".to_string(),
+                scheduled_for: (Utc::now() + TimeDelta::hours(1)).fixed_offset(),
+                description: String::new(),
+                text_options: None,
+                privacy: None,
+                race_duration_secs: None,
+                join_cutoff_secs: None,
+                inactivity_timeout_secs: None,
+                max_participants: None,
+                team_scoring: None,
+                rounds: None,
+                eliminations_per_round: None,
+                mode: None,
+                rematch_of: None,
+            },
+            &user,
+        )
+        .await
+        .unwrap();
+
+        let manager = TournamentManager::new(TournamentSchema::from(tournament), app_state.clone());
+        *manager.inner.typing_text.write().unwrap() = Arc::new("the cat".to_string());
+
+        let member = TournamentRoomMember::from_user(&user, false, true);
+        manager.inner.participants.set_data(
+            &member.id,
+            TypingSessionSchema::new(member.clone(), manager.inner.tournament_id.to_string()),
+        );
+
+        for (correct, current, keystrokes) in [(4, 6, 6), (4, 5, 8), (7, 7, 12)] {
+            manager
+                .apply_progress(&member.id, progress(correct, current, keystrokes))
+                .unwrap()
+                .unwrap();
+        }
+        manager.shutdown().await;
+
+        let rows = key_stats::Entity::find()
+            .filter(key_stats::Column::UserId.eq(&user.id))
+            .all(&app_state.conn)
+            .await
+            .unwrap();
+        let row = |sequence: &str| {
+            let row = rows.iter().find(|row| row.sequence == sequence).unwrap();
+            (row.attempts, row.errors)
+        };
+
+        assert_eq!(row("c"), (2, 1));
+        assert_eq!(row(" c"), (2, 1));
+        assert_eq!(row("t"), (2, 0));
+    }
+}
@@ -1,10 +1,30 @@
+mod achievements;
 mod algorithm;
+mod bracket;
 mod debouncer;
 mod dtos;
+mod key_stats;
+mod lobby;
 mod moderation;
+mod practice;
+mod rating;
+mod recurrence;
+mod replay;
+mod standings;
+mod teams;
 mod timeout;
 
 mod manager;
 
+pub use achievements::*;
+pub use bracket::*;
 pub use dtos::*;
+pub use key_stats::*;
+pub use lobby::*;
 pub use manager::*;
+pub use practice::*;
+pub use rating::*;
+pub use recurrence::*;
+pub use replay::*;
+pub use standings::*;
+pub use teams::*;
@@ -0,0 +1,179 @@
+use std::sync::{Arc, Mutex};
+
+use chrono::Utc;
+use models::schemas::typing::{TextOptions, TypingSessionSchema};
+use models::schemas::user::TournamentRoomMember;
+use socketioxide::extract::{Data, SocketRef};
+use tracing::{error, info, warn};
+
+use crate::core::{
+    TournamentManager, algorithm::*, dtos::*, key_stats::KeyStats, standings::session_outcome,
+};
+use crate::persistence::{
+    practice::record_practice_session,
+    text::{GeneratedText, PassageRevision, generate_text, race_seed},
+};
+use crate::state::AppState;
+
+/// A solo typing test: no room, no schedule and no join deadline. The clock starts
+/// on the first keystroke and the session ends once the whole text is typed.
+pub struct PracticeSession {
+    pub session: TypingSessionSchema,
+    pub text: String,
+    pub text_seed: i64,
+    passage: Option<PassageRevision>,
+    chars: Vec<char>,
+    key_stats: KeyStats,
+}
+
+impl PracticeSession {
+    /// Starts a session on a fresh text.
+    pub async fn start(app_state: &AppState, member: TournamentRoomMember) -> Self {
+        let text_seed = rand::random();
+        let GeneratedText { text, passage } = generate_text(
+            &app_state.conn,
+            TextOptions::default(),
+            race_seed(text_seed, 1, 0),
+        )
+        .await;
+
+        let id_len = crate::persistence::TOURNAMENT_ID_LENGTH;
+        let id = nanoid::nanoid!(id_len, &crate::persistence::ID_ALPHABET);
+
+        Self {
+            session: TypingSessionSchema::new(member, id),
+            chars: text.chars().collect(),
+            text,
+            text_seed,
+            passage,
+            key_stats: KeyStats::default(),
+        }
+    }
+
+    pub fn handle_type(
+        &mut self,
+        input: &[char],
+    ) -> Result<PartialParticipantData, WsFailurePayload> {
+        ZeroProceed.handle_type(&mut self.session, input, &self.chars, &mut self.key_stats)
+    }
+
+    fn started_payload(&self, noauth: String) -> PracticeStartedPayload {
+        PracticeStartedPayload {
+            id: self.session.tournament_id.clone(),
+            text: self.text.clone(),
+            member: self.session.member.clone(),
+            noauth,
+        }
+    }
+}
+
+/// Starts a practice session on a freshly connected socket. `restart` swaps in a
+/// new text and a fresh session at any time.
+pub async fn start_practice(socket: SocketRef, app_state: AppState, noauth: String) {
+    let member = socket
+        .extensions
+        .get::<Arc<TournamentRoomMember>>()
+        .unwrap();
+
+    let practice = PracticeSession::start(&app_state, (*member).clone()).await;
+    let practice = Arc::new(Mutex::new(practice));
+    let started_payload = practice.lock().unwrap().started_payload(noauth);
+
+    if let Err(e) = socket.emit("practice:started", &started_payload) {
+        warn!("Failed to send practice:started to {}: {}", member.id, e);
+    }
+
+    socket.on("restart", {
+        let practice = practice.clone();
+        let member = member.clone();
+        let app_state = app_state.clone();
+        async move |s: SocketRef| {
+            let restarted = PracticeSession::start(&app_state, (*member).clone()).await;
+            let started_payload = {
+                let mut practice = practice.lock().unwrap();
+                *practice = restarted;
+                practice.started_payload(String::new())
+            };
+
+            if let Err(e) = s.emit("practice:started", &started_payload) {
+                warn!("Failed to send practice:started to {}: {}", member.id, e);
+            }
+        }
+    });
+
+    socket.on(
+        "type",
+        async move |s: SocketRef, Data::<TypeEventPayload>(TypeEventPayload { character, rid })| {
+            let (update_result, finished) = {
+                let mut practice = practice.lock().unwrap();
+                let update_result = practice.handle_type(&[character]);
+                let finished = practice.session.ended_at.is_some().then(|| {
+                    let key_stats = std::mem::take(&mut practice.key_stats);
+                    let text = GeneratedText {
+                        text: practice.text.clone(),
+                        passage: practice.passage,
+                    };
+                    (
+                        practice.session.clone(),
+                        text,
+                        practice.text_seed,
+                        key_stats,
+                    )
+                });
+                (update_result, finished)
+            };
+
+            match update_result {
+                Ok(changes) => {
+                    let update_me_payload = UpdateMePayload {
+                        updates: changes,
+                        rid,
+                    };
+                    if let Err(e) = s.emit("update:me", &update_me_payload) {
+                        warn!("Failed to send update:me to {}: {}", member.id, e);
+                    }
+                }
+                Err(failure_payload) => {
+                    s.emit("type:failure", &failure_payload).ok();
+                    return;
+                }
+            }
+
+            let Some((session, text, text_seed, key_stats)) = finished else {
+                return;
+            };
+
+            info!(
+                member_id = %member.id,
+                practice_id = %session.tournament_id,
+                outcome = ?session_outcome(&session),
+                "Practice session finished"
+            );
+
+            let saved = record_practice_session(
+                &app_state.conn,
+                &session,
+                &text,
+                text_seed,
+                key_stats,
+                Utc::now(),
+            )
+            .await
+            .inspect_err(|e| {
+                error!(
+                    "Failed to save practice session {}: {}",
+                    session.tournament_id, e
+                )
+            })
+            .unwrap_or(false);
+
+            let ended_payload = PracticeEndedPayload {
+                participant: TournamentManager::map_session_to_api_participant_data(&session),
+                saved,
+            };
+            if let Err(e) = s.emit("practice:ended", &ended_payload) {
+                warn!("Failed to send practice:ended to {}: {}", member.id, e);
+            }
+        },
+    );
+}
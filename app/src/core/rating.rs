@@ -0,0 +1,54 @@
+pub const DEFAULT_RATING: i32 = 1500;
+
+const K_FACTOR: f64 = 32.0;
+
+/// Multiplayer Elo over final standings.
+///
+/// `ratings` are the current ratings ordered from first to last place. Every participant
+/// plays a virtual match against each other one, winning it when placed higher, and the
+/// summed deltas are scaled by `n - 1` so a race moves a rating about as much as a single
+/// head-to-head game would.
+pub fn rate_standings(ratings: &[i32]) -> Vec<i32> {
+    let opponents = ratings.len().saturating_sub(1);
+    if opponents == 0 {
+        return ratings.to_vec();
+    }
+
+    ratings
+        .iter()
+        .enumerate()
+        .map(|(i, &rating)| {
+            let delta: f64 = ratings
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(j, &opponent)| {
+                    let expected = 1.0 / (1.0 + 10f64.powf((opponent - rating) as f64 / 400.0));
+                    let actual = if i < j { 1.0 } else { 0.0 };
+                    actual - expected
+                })
+                .sum();
+
+            rating + (K_FACTOR * delta / opponents as f64).round() as i32
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_pair_moves_by_half_k() {
+        assert_eq!(rate_standings(&[1500, 1500]), [1516, 1484]);
+    }
+
+    #[test]
+    fn upset_moves_more_than_expected_win() {
+        let expected = rate_standings(&[1800, 1400]);
+        let upset = rate_standings(&[1400, 1800]);
+
+        assert!(upset[0] - 1400 > expected[0] - 1800);
+        assert_eq!(rate_standings(&[1500]), [1500]);
+    }
+}
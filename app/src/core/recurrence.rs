@@ -0,0 +1,62 @@
+use chrono::{DateTime, Datelike, NaiveTime, Utc};
+use models::schemas::series::RecurrenceRule;
+
+/// Start times produced by `rule` within `[from, until]`, in ascending order.
+pub fn occurrences(
+    rule: &RecurrenceRule,
+    from: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> Vec<DateTime<Utc>> {
+    let Some(time) = NaiveTime::from_hms_opt(rule.hour, rule.minute, 0) else {
+        return Vec::new();
+    };
+
+    from.date_naive()
+        .iter_days()
+        .take_while(|day| *day <= until.date_naive())
+        .filter(|day| rule.runs_on(day.weekday()))
+        .map(|day| day.and_time(time).and_utc())
+        .filter(|at| *at >= from && *at <= until)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use models::schemas::series::RecurrenceDay;
+
+    use super::*;
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        // 2025-09-01 is a Monday.
+        Utc.with_ymd_and_hms(2025, 9, day, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn daily_rule_skips_time_already_passed() {
+        let rule = RecurrenceRule {
+            days: Vec::new(),
+            hour: 18,
+            minute: 0,
+        };
+
+        assert_eq!(
+            occurrences(&rule, at(1, 19, 0), at(3, 23, 0)),
+            [at(2, 18, 0), at(3, 18, 0)]
+        );
+    }
+
+    #[test]
+    fn weekly_rule_only_matches_listed_days() {
+        let rule = RecurrenceRule {
+            days: vec![RecurrenceDay::Monday, RecurrenceDay::Thursday],
+            hour: 9,
+            minute: 30,
+        };
+
+        assert_eq!(
+            occurrences(&rule, at(1, 0, 0), at(14, 0, 0)),
+            [at(1, 9, 30), at(4, 9, 30), at(8, 9, 30), at(11, 9, 30)]
+        );
+    }
+}
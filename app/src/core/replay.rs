@@ -0,0 +1,151 @@
+use chrono::{DateTime, Utc};
+use models::schemas::replay::ReplayEventSchema;
+
+/// Append-only keystroke log of a single participant, kept encoded while the race runs.
+///
+/// Every frame is a sequence of LEB128 varints: milliseconds since the previous frame,
+/// the current and correct caret positions after the frame, and the byte length of the
+/// typed keys followed by the keys themselves as UTF-8.
+#[derive(Clone, Debug, Default)]
+pub struct ReplayLog {
+    started_at: Option<DateTime<Utc>>,
+    last_offset_ms: u64,
+    event_count: usize,
+    bytes: Vec<u8>,
+}
+
+impl ReplayLog {
+    pub fn record(
+        &mut self,
+        at: DateTime<Utc>,
+        keys: &[char],
+        current_position: usize,
+        correct_position: usize,
+    ) {
+        let started_at = *self.started_at.get_or_insert(at);
+        let offset_ms = at
+            .signed_duration_since(started_at)
+            .num_milliseconds()
+            .max(0) as u64;
+        let delta_ms = offset_ms.saturating_sub(self.last_offset_ms);
+        self.last_offset_ms = self.last_offset_ms.max(offset_ms);
+
+        let keys: String = keys.iter().collect();
+
+        write_varint(&mut self.bytes, delta_ms);
+        write_varint(&mut self.bytes, current_position as u64);
+        write_varint(&mut self.bytes, correct_position as u64);
+        write_varint(&mut self.bytes, keys.len() as u64);
+        self.bytes.extend_from_slice(keys.as_bytes());
+
+        self.event_count += 1;
+    }
+
+    pub fn started_at(&self) -> Option<DateTime<Utc>> {
+        self.started_at
+    }
+
+    pub fn event_count(&self) -> usize {
+        self.event_count
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Decodes a log produced by [`ReplayLog`]. Returns `None` if the data is truncated or malformed.
+pub fn decode_replay(mut bytes: &[u8]) -> Option<Vec<ReplayEventSchema>> {
+    let mut events = Vec::new();
+    let mut offset_ms = 0u64;
+
+    while !bytes.is_empty() {
+        offset_ms += read_varint(&mut bytes)?;
+        let current_position = read_varint(&mut bytes)? as usize;
+        let correct_position = read_varint(&mut bytes)? as usize;
+        let keys_len = read_varint(&mut bytes)? as usize;
+
+        if bytes.len() < keys_len {
+            return None;
+        }
+        let (keys, rest) = bytes.split_at(keys_len);
+        bytes = rest;
+
+        events.push(ReplayEventSchema {
+            offset_ms,
+            keys: String::from_utf8(keys.to_vec()).ok()?,
+            current_position,
+            correct_position,
+        });
+    }
+
+    Some(events)
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf.push(byte);
+            return;
+        }
+        buf.push(byte | 0x80);
+    }
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = bytes.split_first()?;
+        *bytes = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use super::*;
+
+    #[test]
+    fn replay_round_trips() {
+        let start = Utc::now();
+        let mut log = ReplayLog::default();
+        log.record(start, &['h', 'é'], 2, 1);
+        log.record(start + TimeDelta::milliseconds(300), &['\u{8}'], 1, 1);
+        log.record(start + TimeDelta::milliseconds(70_000), &[], 40, 38);
+
+        assert_eq!(log.started_at(), Some(start));
+        assert_eq!(log.event_count(), 3);
+
+        let events = decode_replay(&log.into_bytes()).unwrap();
+        let offsets: Vec<u64> = events.iter().map(|e| e.offset_ms).collect();
+
+        assert_eq!(offsets, [0, 300, 70_000]);
+        assert_eq!(events[0].keys, "hé");
+        assert_eq!(events[1].keys, "\u{8}");
+        assert_eq!(events[2].keys, "");
+        assert_eq!(
+            (events[2].current_position, events[2].correct_position),
+            (40, 38)
+        );
+    }
+
+    #[test]
+    fn truncated_replay_is_rejected() {
+        let mut log = ReplayLog::default();
+        log.record(Utc::now(), &['a', 'b'], 2, 2);
+        let mut bytes = log.into_bytes();
+        bytes.pop();
+
+        assert_eq!(decode_replay(&bytes), None);
+    }
+}
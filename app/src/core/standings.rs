@@ -0,0 +1,133 @@
+use std::cmp::Ordering;
+
+use models::{domains::sea_orm_active_enums::RaceOutcome, schemas::typing::TypingSessionSchema};
+
+pub fn session_outcome(session: &TypingSessionSchema) -> RaceOutcome {
+    if session.timed_out {
+        RaceOutcome::TimedOut
+    } else if session.ended_at.is_some() {
+        RaceOutcome::Finished
+    } else {
+        RaceOutcome::Dnf
+    }
+}
+
+pub fn session_duration_ms(session: &TypingSessionSchema) -> Option<i64> {
+    match (session.started_at, session.ended_at) {
+        (Some(started_at), Some(ended_at)) => Some(
+            ended_at
+                .signed_duration_since(started_at)
+                .num_milliseconds(),
+        ),
+        _ => None,
+    }
+}
+
+/// Orders sessions from first to last place.
+///
+/// Finishers come first, fastest finish time first, then highest accuracy.
+/// Everyone else is ordered by how far they got, then by accuracy.
+/// The member id breaks any remaining tie, so the order never depends on cache iteration.
+pub fn rank_sessions(mut sessions: Vec<TypingSessionSchema>) -> Vec<TypingSessionSchema> {
+    sessions.sort_by(compare_sessions);
+    sessions
+}
+
+/// Orders time-attack sessions: most correct characters first, then highest accuracy.
+/// Finishing doesn't count there, everyone races the same clock.
+pub fn rank_by_correct_chars(mut sessions: Vec<TypingSessionSchema>) -> Vec<TypingSessionSchema> {
+    sessions.sort_by(|a, b| {
+        b.correct_position
+            .cmp(&a.correct_position)
+            .then_with(|| b.current_accuracy.total_cmp(&a.current_accuracy))
+            .then_with(|| a.member.id.cmp(&b.member.id))
+    });
+    sessions
+}
+
+fn compare_sessions(a: &TypingSessionSchema, b: &TypingSessionSchema) -> Ordering {
+    let a_finished = session_outcome(a) == RaceOutcome::Finished;
+    let b_finished = session_outcome(b) == RaceOutcome::Finished;
+
+    let by_progress = if a_finished && b_finished {
+        let a_duration = session_duration_ms(a).unwrap_or(i64::MAX);
+        let b_duration = session_duration_ms(b).unwrap_or(i64::MAX);
+        a_duration.cmp(&b_duration)
+    } else {
+        b_finished
+            .cmp(&a_finished)
+            .then_with(|| b.correct_position.cmp(&a.correct_position))
+    };
+
+    by_progress
+        .then_with(|| b.current_accuracy.total_cmp(&a.current_accuracy))
+        .then_with(|| a.member.id.cmp(&b.member.id))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, Utc};
+    use models::schemas::user::TournamentRoomMember;
+
+    use super::*;
+
+    fn session(
+        id: &str,
+        duration_ms: Option<i64>,
+        correct: usize,
+        accuracy: f32,
+    ) -> TypingSessionSchema {
+        let member = TournamentRoomMember {
+            id: id.into(),
+            user: None,
+            participant: true,
+            rating: None,
+            user_id: None,
+            team: None,
+        };
+        let mut session = TypingSessionSchema::new(member, "t".into());
+        let started_at = Utc::now();
+        session.started_at = Some(started_at);
+        session.ended_at = duration_ms.map(|ms| started_at + TimeDelta::milliseconds(ms));
+        session.correct_position = correct;
+        session.current_accuracy = accuracy;
+        session
+    }
+
+    fn ids(sessions: &[TypingSessionSchema]) -> Vec<&str> {
+        sessions.iter().map(|s| s.member.id.as_str()).collect()
+    }
+
+    #[test]
+    fn ties_break_by_finish_time_then_accuracy() {
+        let ranked = rank_sessions(vec![
+            session("d", None, 50, 100.0),
+            session("c", Some(20_000), 100, 90.0),
+            session("b", Some(10_000), 100, 90.0),
+            session("a", Some(10_000), 100, 95.0),
+        ]);
+
+        assert_eq!(ids(&ranked), ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn ranking_is_independent_of_input_order() {
+        let ranked = rank_sessions(vec![
+            session("y", None, 40, 90.0),
+            session("x", None, 40, 90.0),
+        ]);
+
+        assert_eq!(ids(&ranked), ["x", "y"]);
+    }
+
+    #[test]
+    fn time_attack_ranks_by_correct_characters() {
+        let ranked = rank_by_correct_chars(vec![
+            session("c", Some(60_000), 300, 99.0),
+            session("b", None, 320, 90.0),
+            session("a", None, 320, 95.0),
+        ]);
+
+        assert_eq!(ids(&ranked), ["a", "b", "c"]);
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+
+use models::{
+    domains::sea_orm_active_enums::TeamScoring,
+    schemas::{tournament::TeamStandingSchema, typing::TypingSessionSchema},
+};
+
+/// Orders the teams of a tournament from first to last place.
+///
+/// Each member contributes their current WPM; `scoring` decides whether a team's
+/// score is the sum or the average of its members'. Equal scores go to the team
+/// that typed more correct characters, then to the team name. Members without a
+/// team are left out.
+pub fn rank_teams(
+    sessions: &[TypingSessionSchema],
+    scoring: TeamScoring,
+) -> Vec<TeamStandingSchema> {
+    let mut teams: BTreeMap<&str, (Vec<f32>, usize)> = BTreeMap::new();
+
+    for session in sessions {
+        let Some(team) = session.member.team.as_deref() else {
+            continue;
+        };
+
+        let (speeds, correct) = teams.entry(team).or_default();
+        speeds.push(session.current_speed);
+        *correct += session.correct_position;
+    }
+
+    let mut teams = teams
+        .into_iter()
+        .map(|(name, (speeds, correct))| {
+            let total = speeds.iter().sum::<f32>();
+            let score = match scoring {
+                TeamScoring::Sum => total,
+                TeamScoring::Average => total / speeds.len() as f32,
+            };
+            (name, score.round() as i32, speeds.len() as i32, correct)
+        })
+        .collect::<Vec<_>>();
+
+    // The map is ordered by name, so a stable sort keeps name order among ties.
+    teams.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| b.3.cmp(&a.3)));
+
+    teams
+        .into_iter()
+        .enumerate()
+        .map(
+            |(index, (name, score, member_count, _))| TeamStandingSchema {
+                rank: index as i32 + 1,
+                name: name.to_owned(),
+                score,
+                member_count,
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use models::schemas::user::TournamentRoomMember;
+
+    use super::*;
+
+    fn session(id: &str, team: Option<&str>, speed: f32, correct: usize) -> TypingSessionSchema {
+        let member = TournamentRoomMember {
+            id: id.into(),
+            user: None,
+            participant: true,
+            rating: None,
+            user_id: None,
+            team: team.map(Into::into),
+        };
+        let mut session = TypingSessionSchema::new(member, "t".into());
+        session.current_speed = speed;
+        session.correct_position = correct;
+        session
+    }
+
+    fn names(teams: &[TeamStandingSchema]) -> Vec<(&str, i32)> {
+        teams
+            .iter()
+            .map(|team| (team.name.as_str(), team.score))
+            .collect()
+    }
+
+    #[test]
+    fn scoring_decides_between_big_and_fast_teams() {
+        let sessions = vec![
+            session("a", Some("red"), 60.0, 10),
+            session("b", Some("red"), 60.0, 10),
+            session("c", Some("red"), 60.0, 10),
+            session("d", Some("blue"), 100.0, 10),
+            session("e", None, 200.0, 10),
+        ];
+
+        assert_eq!(
+            names(&rank_teams(&sessions, TeamScoring::Sum)),
+            [("red", 180), ("blue", 100)]
+        );
+        assert_eq!(
+            names(&rank_teams(&sessions, TeamScoring::Average)),
+            [("blue", 100), ("red", 60)]
+        );
+    }
+
+    #[test]
+    fn ties_break_by_progress_then_name() {
+        let sessions = vec![
+            session("a", Some("c"), 50.0, 10),
+            session("b", Some("b"), 50.0, 20),
+            session("c", Some("a"), 50.0, 10),
+        ];
+
+        assert_eq!(
+            names(&rank_teams(&sessions, TeamScoring::Sum)),
+            [("b", 50), ("a", 50), ("c", 50)]
+        );
+    }
+}
@@ -134,3 +134,25 @@ fn execute_and_schedule_cleanup<Fut, TaskFut>(
         std::mem::drop(timeout_state_lock);
     });
 }
+
+/// A replaced monitor must not time its member out later on.
+impl<FAfter, Fut, AFutAfter> Drop for TimeoutMonitor<FAfter, Fut, AFutAfter>
+where
+    Fut: Future<Output = ()> + Send + 'static,
+    FAfter: Fn() -> AFutAfter + Send + Sync + 'static,
+    AFutAfter: Future<Output = ()> + Send + 'static,
+{
+    fn drop(&mut self) {
+        let Ok(mut state) = self.inner.try_lock() else {
+            return;
+        };
+
+        if let TimeoutState::Active {
+            current_cleanup_handle: Some(handle),
+            ..
+        } = &mut *state
+        {
+            handle.abort();
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, FixedOffset, Utc};
+use models::domains::{
+    sea_orm_active_enums::RaceOutcome, tournaments, typing_history, user_achievements,
+};
+use models::schemas::achievement::AchievementSchema;
+use sea_orm::sea_query::{Expr, Func, SimpleExpr};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbConn, DbErr, EntityTrait, FromQueryResult, JoinType,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Select, Set,
+};
+
+use crate::core::{ACHIEVEMENTS, Achievement, AchievementProgress, DayStreak, find_achievement};
+
+/// Races read per query while walking back through a day streak.
+const STREAK_PAGE_SIZE: u64 = 64;
+
+#[derive(FromQueryResult)]
+struct FinishedRow {
+    races: i64,
+    wins: Option<i64>,
+    best_speed: Option<i32>,
+    perfect_races: Option<i64>,
+}
+
+fn to_schema(achievement: &Achievement, unlocked_at: DateTime<Utc>) -> AchievementSchema {
+    AchievementSchema {
+        id: achievement.id.to_owned(),
+        title: achievement.title.to_owned(),
+        description: achievement.description.to_owned(),
+        unlocked_at,
+    }
+}
+
+/// Runs every registered rule against the user's history and stores the new unlocks.
+///
+/// Meant to run right after the race has been written to `typing_history`, so the
+/// latest race already counts. Practice runs don't, the badges are for tournaments.
+/// Returns only the achievements unlocked by this call.
+pub async fn unlock_achievements<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    tournament_id: &str,
+    unlocked_at: DateTime<Utc>,
+) -> Result<Vec<AchievementSchema>, DbErr> {
+    let unlocked = user_achievements::Entity::find()
+        .filter(user_achievements::Column::UserId.eq(user_id))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|unlock| unlock.achievement_id)
+        .collect::<HashSet<_>>();
+
+    if ACHIEVEMENTS.iter().all(|a| unlocked.contains(a.id)) {
+        return Ok(Vec::new());
+    }
+
+    let progress = achievement_progress(db, user_id).await?;
+
+    let new_unlocks = ACHIEVEMENTS
+        .iter()
+        .filter(|a| !unlocked.contains(a.id) && (a.rule)(&progress))
+        .collect::<Vec<_>>();
+
+    if new_unlocks.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let records = new_unlocks
+        .iter()
+        .map(|achievement| user_achievements::ActiveModel {
+            user_id: Set(user_id.to_owned()),
+            achievement_id: Set(achievement.id.to_owned()),
+            tournament_id: Set(Some(tournament_id.to_owned())),
+            unlocked_at: Set(unlocked_at.fixed_offset()),
+            ..Default::default()
+        });
+
+    user_achievements::Entity::insert_many(records)
+        .exec(db)
+        .await?;
+
+    Ok(new_unlocks
+        .into_iter()
+        .map(|achievement| to_schema(achievement, unlocked_at))
+        .collect())
+}
+
+async fn achievement_progress<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+) -> Result<AchievementProgress, DbErr> {
+    let races = typing_history::Entity::find()
+        .join(
+            JoinType::InnerJoin,
+            typing_history::Relation::Tournaments.def(),
+        )
+        .filter(tournaments::Column::Practice.eq(false))
+        .filter(typing_history::Column::UserId.eq(user_id));
+
+    let finished = races
+        .clone()
+        .filter(typing_history::Column::Outcome.eq(RaceOutcome::Finished))
+        .select_only()
+        .column_as(typing_history::Column::Id.count(), "races")
+        .column_as(
+            SimpleExpr::from(Func::sum(
+                Expr::case(typing_history::Column::Placement.eq(1), 1).finally(0),
+            )),
+            "wins",
+        )
+        .column_as(typing_history::Column::Speed.max(), "best_speed")
+        .column_as(
+            SimpleExpr::from(Func::sum(
+                Expr::case(typing_history::Column::Accuracy.gte(100), 1).finally(0),
+            )),
+            "perfect_races",
+        )
+        .into_model::<FinishedRow>()
+        .one(db)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Achievement progress".to_owned()))?;
+
+    Ok(AchievementProgress {
+        races: races.clone().count(db).await?,
+        finished_races: finished.races as u64,
+        wins: finished.wins.unwrap_or(0) as u64,
+        best_speed: finished.best_speed.unwrap_or(0),
+        perfect_races: finished.perfect_races.unwrap_or(0) as u64,
+        day_streak: day_streak(db, races).await?,
+    })
+}
+
+/// Reads race times newest first, a page at a time, until a day without a race.
+async fn day_streak<C: ConnectionTrait>(
+    db: &C,
+    races: Select<typing_history::Entity>,
+) -> Result<u32, DbErr> {
+    let mut pages = races
+        .select_only()
+        .column(typing_history::Column::CompletedAt)
+        .order_by_desc(typing_history::Column::CompletedAt)
+        .order_by_desc(typing_history::Column::Id)
+        .into_tuple::<DateTime<FixedOffset>>()
+        .paginate(db, STREAK_PAGE_SIZE);
+
+    let mut streak = DayStreak::default();
+    while let Some(completed) = pages.fetch_and_next().await? {
+        for completed_at in completed {
+            if !streak.push(completed_at.to_utc().date_naive()) {
+                return Ok(streak.days());
+            }
+        }
+    }
+
+    Ok(streak.days())
+}
+
+pub async fn get_user_achievements(
+    db: &DbConn,
+    user_id: &str,
+) -> Result<Vec<AchievementSchema>, DbErr> {
+    let unlocks = user_achievements::Entity::find()
+        .filter(user_achievements::Column::UserId.eq(user_id))
+        .order_by_asc(user_achievements::Column::UnlockedAt)
+        .order_by_asc(user_achievements::Column::Id)
+        .all(db)
+        .await?;
+
+    Ok(unlocks
+        .into_iter()
+        .filter_map(|unlock| {
+            let achievement = find_achievement(&unlock.achievement_id)?;
+            Some(to_schema(achievement, unlock.unlocked_at.to_utc()))
+        })
+        .collect())
+}
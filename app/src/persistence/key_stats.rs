@@ -0,0 +1,129 @@
+use std::cmp::Ordering;
+
+use models::domains::key_stats;
+use models::queries::key_stats::WeakKeysQuery;
+use models::schemas::key_stats::{KeyErrorSchema, WeakKeysSchema};
+use sea_orm::sea_query::{Alias, Expr, OnConflict};
+use sea_orm::{ColumnTrait, ConnectionTrait, DbConn, DbErr, EntityTrait, QueryFilter, Set};
+
+use crate::core::KeyStats;
+
+pub const DEFAULT_WEAK_KEYS_MIN_ATTEMPTS: u64 = 10;
+pub const DEFAULT_WEAK_KEYS_LIMIT: u64 = 10;
+pub const MAX_WEAK_KEYS_LIMIT: u64 = 50;
+
+/// Adds each user's tallies from a finished race onto their lifetime totals.
+pub async fn record_key_stats<C: ConnectionTrait>(
+    db: &C,
+    stats: Vec<(String, KeyStats)>,
+) -> Result<(), DbErr> {
+    for (user_id, stats) in stats {
+        if stats.is_empty() {
+            continue;
+        }
+
+        let keys = stats
+            .keys
+            .iter()
+            .map(|(key, tally)| (key.to_string(), tally));
+        let bigrams = stats
+            .bigrams
+            .iter()
+            .map(|(&(previous, key), tally)| (format!("{previous}{key}"), tally));
+
+        let records = keys
+            .chain(bigrams)
+            .map(|(sequence, tally)| key_stats::ActiveModel {
+                user_id: Set(user_id.clone()),
+                sequence: Set(sequence),
+                attempts: Set(tally.attempts.into()),
+                errors: Set(tally.errors.into()),
+                latency_ms: Set(tally.latency_ms as i64),
+                latency_samples: Set(tally.latency_samples.into()),
+                ..Default::default()
+            });
+
+        key_stats::Entity::insert_many(records)
+            .on_conflict(accumulate_on_conflict())
+            .exec(db)
+            .await?;
+    }
+
+    Ok(())
+}
+
+fn accumulate_on_conflict() -> OnConflict {
+    let mut on_conflict =
+        OnConflict::columns([key_stats::Column::UserId, key_stats::Column::Sequence]);
+
+    for column in [
+        key_stats::Column::Attempts,
+        key_stats::Column::Errors,
+        key_stats::Column::LatencyMs,
+        key_stats::Column::LatencySamples,
+    ] {
+        on_conflict.value(
+            column,
+            Expr::col((key_stats::Entity, column)).add(Expr::col((Alias::new("excluded"), column))),
+        );
+    }
+
+    on_conflict
+}
+
+pub async fn get_weak_keys(
+    db: &DbConn,
+    user_id: &str,
+    query: WeakKeysQuery,
+) -> Result<WeakKeysSchema, DbErr> {
+    let min_attempts = query.min_attempts.unwrap_or(DEFAULT_WEAK_KEYS_MIN_ATTEMPTS) as i64;
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_WEAK_KEYS_LIMIT)
+        .clamp(1, MAX_WEAK_KEYS_LIMIT) as usize;
+
+    let records = key_stats::Entity::find()
+        .filter(key_stats::Column::UserId.eq(user_id))
+        .filter(key_stats::Column::Attempts.gte(min_attempts))
+        .all(db)
+        .await?;
+
+    let (mut keys, mut bigrams): (Vec<_>, Vec<_>) = records
+        .into_iter()
+        .map(key_error)
+        .partition(|entry| entry.sequence.chars().count() == 1);
+
+    for entries in [&mut keys, &mut bigrams] {
+        entries.sort_by(compare_weakness);
+        entries.truncate(limit);
+    }
+
+    Ok(WeakKeysSchema {
+        user_id: user_id.to_owned(),
+        keys,
+        bigrams,
+    })
+}
+
+fn key_error(record: key_stats::Model) -> KeyErrorSchema {
+    KeyErrorSchema {
+        error_rate: if record.attempts > 0 {
+            record.errors as f32 / record.attempts as f32
+        } else {
+            0.0
+        },
+        average_latency_ms: (record.latency_samples > 0)
+            .then(|| record.latency_ms as f32 / record.latency_samples as f32),
+        sequence: record.sequence,
+        attempts: record.attempts,
+        errors: record.errors,
+    }
+}
+
+/// Highest error rate first; more attempts win ties since the rate is more reliable.
+fn compare_weakness(a: &KeyErrorSchema, b: &KeyErrorSchema) -> Ordering {
+    b.error_rate
+        .total_cmp(&a.error_rate)
+        .then_with(|| b.attempts.cmp(&a.attempts))
+        .then_with(|| a.sequence.cmp(&b.sequence))
+}
@@ -0,0 +1,142 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, NaiveTime, Utc};
+use models::domains::{sea_orm_active_enums::RaceOutcome, tournaments, typing_history, users};
+use models::queries::leaderboard::{LeaderboardMetric, LeaderboardPeriod, LeaderboardQuery};
+use models::schemas::leaderboard::{LeaderboardEntrySchema, LeaderboardSchema};
+use sea_orm::sea_query::{Alias, Expr, Func, SimpleExpr};
+use sea_orm::{
+    ColumnTrait, DbErr, EntityTrait, FromQueryResult, JoinType, Order, QueryFilter, QueryOrder,
+    QuerySelect, RelationTrait,
+};
+
+use crate::state::AppState;
+
+pub const LEADERBOARD_CACHE_TTL: Duration = Duration::from_secs(60);
+
+const DEFAULT_MIN_ACCURACY: i32 = 90;
+const DEFAULT_LEADERBOARD_SIZE: u64 = 50;
+/// Boards are computed and cached at this size; smaller limits are served from the same entry.
+const MAX_LEADERBOARD_SIZE: u64 = 100;
+
+#[derive(FromQueryResult)]
+struct LeaderboardRow {
+    user_id: String,
+    username: String,
+    score: f64,
+    races: i64,
+}
+
+/// Serves a leaderboard, computing it from `typing_history` only when the cached copy is stale.
+/// Practice runs can be restarted at will, so only tournament races rank.
+pub async fn get_leaderboard(
+    state: &AppState,
+    query: LeaderboardQuery,
+) -> Result<LeaderboardSchema, DbErr> {
+    let period = query.period.unwrap_or_default();
+    let metric = query.metric.unwrap_or_default();
+    let min_accuracy = query
+        .min_accuracy
+        .unwrap_or(DEFAULT_MIN_ACCURACY)
+        .clamp(0, 100);
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_LEADERBOARD_SIZE)
+        .clamp(1, MAX_LEADERBOARD_SIZE) as usize;
+
+    let cache_key = format!("{:?}:{:?}:{}", period, metric, min_accuracy);
+
+    let board = match state.leaderboard_cache.get(&cache_key) {
+        Some(board) => board,
+        None => {
+            let board = compute_leaderboard(state, period, metric, min_accuracy).await?;
+            state.leaderboard_cache.set(&cache_key, board)
+        }
+    };
+
+    let mut board = Arc::unwrap_or_clone(board);
+    board.entries.truncate(limit);
+
+    Ok(board)
+}
+
+async fn compute_leaderboard(
+    state: &AppState,
+    period: LeaderboardPeriod,
+    metric: LeaderboardMetric,
+    min_accuracy: i32,
+) -> Result<LeaderboardSchema, DbErr> {
+    let now = Utc::now();
+
+    let score: SimpleExpr = match metric {
+        LeaderboardMetric::Best => Func::max(Expr::col(typing_history::Column::Speed)).into(),
+        LeaderboardMetric::Average => Func::avg(Expr::col(typing_history::Column::Speed)).into(),
+    };
+
+    let mut sql_query = typing_history::Entity::find()
+        .select_only()
+        .column(typing_history::Column::UserId)
+        .column_as(users::Column::Username, "username")
+        .column_as(score.cast_as(Alias::new("double precision")), "score")
+        .column_as(typing_history::Column::Id.count(), "races")
+        .join(JoinType::InnerJoin, typing_history::Relation::Users.def())
+        .join(
+            JoinType::InnerJoin,
+            typing_history::Relation::Tournaments.def(),
+        )
+        .filter(tournaments::Column::Practice.eq(false))
+        .filter(typing_history::Column::Outcome.eq(RaceOutcome::Finished))
+        .filter(typing_history::Column::Anonymous.eq(false))
+        .filter(typing_history::Column::Accuracy.gte(min_accuracy));
+
+    if let Some(since) = period_start(period, now) {
+        sql_query = sql_query.filter(typing_history::Column::CompletedAt.gte(since));
+    }
+
+    let rows = sql_query
+        .group_by(typing_history::Column::UserId)
+        .group_by(users::Column::Username)
+        .order_by(Expr::col(Alias::new("score")), Order::Desc)
+        .order_by(Expr::col(Alias::new("races")), Order::Desc)
+        .order_by_asc(typing_history::Column::UserId)
+        .limit(MAX_LEADERBOARD_SIZE)
+        .into_model::<LeaderboardRow>()
+        .all(&state.conn)
+        .await?;
+
+    let entries = rows
+        .into_iter()
+        .enumerate()
+        .map(|(index, row)| LeaderboardEntrySchema {
+            rank: index + 1,
+            user_id: row.user_id,
+            username: row.username,
+            score: row.score as f32,
+            races: row.races,
+        })
+        .collect();
+
+    Ok(LeaderboardSchema {
+        period,
+        metric,
+        min_accuracy,
+        generated_at: now,
+        entries,
+    })
+}
+
+/// Start of the calendar period (UTC) containing `now`; boards reset at midnight,
+/// on Mondays and on the first of the month respectively.
+fn period_start(period: LeaderboardPeriod, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let today = now.date_naive();
+    let start = match period {
+        LeaderboardPeriod::Daily => today,
+        LeaderboardPeriod::Weekly => {
+            today - ChronoDuration::days(today.weekday().num_days_from_monday() as i64)
+        }
+        LeaderboardPeriod::Monthly => today.with_day(1)?,
+        LeaderboardPeriod::AllTime => return None,
+    };
+
+    Some(start.and_time(NaiveTime::MIN).and_utc())
+}
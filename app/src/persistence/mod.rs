@@ -1,5 +1,19 @@
+pub mod achievements;
+pub mod key_stats;
+pub mod leaderboards;
+pub mod passages;
+pub mod practice;
+pub mod race_replays;
+pub mod ratings;
+pub mod series;
+pub mod stats;
 pub mod text;
+pub mod tournament_bans;
+pub mod tournament_invites;
+pub mod tournament_results;
+pub mod tournament_texts;
 pub mod tournaments;
+pub mod typing_history;
 pub mod users;
 
 pub const ID_ALPHABET: [char; 62] = [
@@ -11,3 +25,4 @@ pub const ID_ALPHABET: [char; 62] = [
 
 pub const USER_ID_LENGTH: usize = 8;
 pub const TOURNAMENT_ID_LENGTH: usize = 12;
+pub const INVITE_CODE_LENGTH: usize = 10;
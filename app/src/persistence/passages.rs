@@ -0,0 +1,339 @@
+use chrono::Utc;
+use models::domains::passages;
+use models::domains::sea_orm_active_enums::{PassageStatus, TextDifficulty, TextLanguage};
+use models::params::passage::{CreatePassageParams, UpdatePassageParams};
+use models::queries::passage::PassageQuery;
+use models::schemas::pagination::PaginatedData;
+use models::schemas::passage::PassageSchema;
+use sea_orm::sea_query::{Alias, Expr};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DbConn, DbErr, EntityTrait, IntoActiveModel, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set,
+};
+
+const MAX_PASSAGE_PAGE_SIZE: u64 = 100;
+/// How far, as a share of the words asked for, a passage drawn for a race may be
+/// shorter or longer than that.
+const LENGTH_TOLERANCE: f64 = 0.5;
+
+/// Passages are raced as a single line, so line breaks and runs of spaces
+/// collapse into single spaces.
+pub fn normalise_passage_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Chinese and Japanese don't put spaces between words.
+fn is_unspaced(language: TextLanguage) -> bool {
+    matches!(language, TextLanguage::Ja | TextLanguage::Zh)
+}
+
+/// Words of an unspaced language are counted as this many characters each,
+/// about the length of a typical word.
+const UNSPACED_CHARS_PER_WORD: usize = 2;
+
+pub fn count_words(text: &str, language: TextLanguage) -> usize {
+    if is_unspaced(language) {
+        let chars = text.chars().filter(|c| c.is_alphanumeric()).count();
+        return chars.div_ceil(UNSPACED_CHARS_PER_WORD);
+    }
+
+    text.split_whitespace().count()
+}
+
+/// Long words and a high share of capitals, digits and punctuation make a
+/// passage harder to type. Unspaced languages have no word length to go by,
+/// so only their digits and punctuation count.
+pub fn estimate_difficulty(text: &str, language: TextLanguage) -> TextDifficulty {
+    let chars = text.chars().filter(|c| !c.is_whitespace()).count().max(1) as f64;
+    let words = count_words(text, language).max(1) as f64;
+    let awkward = text
+        .chars()
+        .filter(|c| !c.is_whitespace() && (!c.is_alphabetic() || c.is_uppercase()))
+        .count() as f64;
+
+    let word_length = if is_unspaced(language) {
+        0.0
+    } else {
+        chars / words
+    };
+    let score = word_length + awkward / chars * 20.0;
+    if score < 4.5 {
+        TextDifficulty::Easy
+    } else if score < 6.5 {
+        TextDifficulty::Medium
+    } else {
+        TextDifficulty::Hard
+    }
+}
+
+/// Tags are lowercase and hyphenated, e.g. `Book Excerpt` becomes `book-excerpt`.
+fn normalise_tag(tag: &str) -> String {
+    tag.trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_whitespace() { '-' } else { c })
+        .filter(|c| c.is_alphanumeric() || *c == '-')
+        .collect()
+}
+
+fn normalise_tags(tags: Vec<String>) -> serde_json::Value {
+    let mut normalised: Vec<String> = Vec::new();
+    for tag in tags.iter().map(|tag| normalise_tag(tag)) {
+        if !tag.is_empty() && !normalised.contains(&tag) {
+            normalised.push(tag);
+        }
+    }
+    serde_json::to_value(normalised).unwrap()
+}
+
+/// Uploads a passage. It stays out of races until approved.
+pub async fn create_passage(
+    db: &DbConn,
+    params: CreatePassageParams,
+) -> Result<PassageSchema, DbErr> {
+    let text = normalise_passage_text(&params.text);
+    let language = params.language.unwrap_or_default();
+    let now = Utc::now().fixed_offset();
+
+    let passage = passages::ActiveModel {
+        word_count: Set(count_words(&text, language) as i32),
+        revision: Set(1),
+        difficulty: Set(params
+            .difficulty
+            .unwrap_or_else(|| estimate_difficulty(&text, language))),
+        language: Set(language),
+        text: Set(text),
+        source: Set(params.source.trim().to_owned()),
+        author: Set(params.author.map(|author| author.trim().to_owned())),
+        tags: Set(normalise_tags(params.tags.unwrap_or_default())),
+        status: Set(PassageStatus::Pending),
+        created_at: Set(now),
+        updated_at: Set(now),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+
+    Ok(passage.into())
+}
+
+pub async fn get_passage(db: &DbConn, id: i32) -> Result<Option<PassageSchema>, DbErr> {
+    Ok(passages::Entity::find_by_id(id)
+        .one(db)
+        .await?
+        .map(PassageSchema::from))
+}
+
+/// Lists passages, newest first.
+pub async fn get_passages(
+    db: &DbConn,
+    query: PassageQuery,
+) -> Result<PaginatedData<PassageSchema>, DbErr> {
+    let limit = query.limit.unwrap_or(20).clamp(1, MAX_PASSAGE_PAGE_SIZE);
+    let page = query.page.unwrap_or(1).max(1);
+    let offset = (page - 1) * limit;
+
+    let mut sql_query = passages::Entity::find();
+
+    if let Some(status) = query.status {
+        sql_query = sql_query.filter(passages::Column::Status.eq(status));
+    }
+
+    if let Some(difficulty) = query.difficulty {
+        sql_query = sql_query.filter(passages::Column::Difficulty.eq(difficulty));
+    }
+
+    if let Some(language) = query.language {
+        sql_query = sql_query.filter(passages::Column::Language.eq(language));
+    }
+
+    // Tags are stored normalised, so a quoted tag can't match part of another one.
+    let tag = query.tag.as_deref().map(normalise_tag).unwrap_or_default();
+    if !tag.is_empty() {
+        sql_query = sql_query.filter(
+            Expr::col(passages::Column::Tags)
+                .cast_as(Alias::new("TEXT"))
+                .like(format!("%\"{tag}\"%")),
+        );
+    }
+
+    let total = sql_query.clone().count(db).await?;
+
+    let data = sql_query
+        .order_by_desc(passages::Column::CreatedAt)
+        .order_by_desc(passages::Column::Id)
+        .offset(offset)
+        .limit(limit)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(PassageSchema::from)
+        .collect();
+
+    Ok(PaginatedData::new(data, page, limit, total))
+}
+
+/// Edits a passage. Changing the text of an approved passage sends it back for
+/// approval, and its difficulty is estimated again unless one is given. The
+/// words are counted again whenever the text or language changes.
+pub async fn update_passage(
+    db: &DbConn,
+    id: i32,
+    params: UpdatePassageParams,
+) -> Result<Option<PassageSchema>, DbErr> {
+    let Some(passage) = passages::Entity::find_by_id(id).one(db).await? else {
+        return Ok(None);
+    };
+    let status = passage.status;
+    let revision = passage.revision;
+    let language = params.language.unwrap_or(passage.language);
+    let text = params
+        .text
+        .as_deref()
+        .map(normalise_passage_text)
+        .unwrap_or_else(|| passage.text.clone());
+    let mut passage = passage.into_active_model();
+
+    if params.text.is_some() || params.language.is_some() {
+        passage.word_count = Set(count_words(&text, language) as i32);
+    }
+
+    if params.text.is_some() {
+        passage.difficulty = Set(estimate_difficulty(&text, language));
+        passage.text = Set(text);
+        passage.revision = Set(revision + 1);
+        if status == PassageStatus::Approved {
+            passage.status = Set(PassageStatus::Pending);
+        }
+    }
+
+    if let Some(source) = params.source {
+        passage.source = Set(source.trim().to_owned());
+    }
+
+    if let Some(author) = params.author {
+        passage.author = Set(Some(author.trim().to_owned()));
+    }
+
+    if let Some(tags) = params.tags {
+        passage.tags = Set(normalise_tags(tags));
+    }
+
+    if let Some(difficulty) = params.difficulty {
+        passage.difficulty = Set(difficulty);
+    }
+
+    passage.language = Set(language);
+
+    passage.updated_at = Set(Utc::now().fixed_offset());
+    let passage = passage.update(db).await?;
+
+    Ok(Some(passage.into()))
+}
+
+/// Approves or retires a passage.
+pub async fn set_passage_status(
+    db: &DbConn,
+    id: i32,
+    status: PassageStatus,
+) -> Result<Option<PassageSchema>, DbErr> {
+    let Some(passage) = passages::Entity::find_by_id(id).one(db).await? else {
+        return Ok(None);
+    };
+
+    let mut passage = passage.into_active_model();
+    passage.status = Set(status);
+    passage.updated_at = Set(Utc::now().fixed_offset());
+    let passage = passage.update(db).await?;
+
+    Ok(Some(passage.into()))
+}
+
+/// Approved passages in `language` fit for a text of about `word_count` words,
+/// ordered by id.
+pub async fn race_passage_ids(
+    db: &DbConn,
+    word_count: u32,
+    difficulty: Option<TextDifficulty>,
+    language: TextLanguage,
+) -> Result<Vec<i32>, DbErr> {
+    let min_words = (f64::from(word_count) * (1.0 - LENGTH_TOLERANCE)).floor() as i32;
+    let max_words = (f64::from(word_count) * (1.0 + LENGTH_TOLERANCE)).ceil() as i32;
+
+    let mut sql_query = passages::Entity::find()
+        .select_only()
+        .column(passages::Column::Id)
+        .filter(passages::Column::Status.eq(PassageStatus::Approved))
+        .filter(passages::Column::Language.eq(language))
+        .filter(passages::Column::WordCount.between(min_words, max_words));
+
+    if let Some(difficulty) = difficulty {
+        sql_query = sql_query.filter(passages::Column::Difficulty.eq(difficulty));
+    }
+
+    sql_query
+        .order_by_asc(passages::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passages_are_raced_on_one_line() {
+        let text =
+            normalise_passage_text("  It was the best of times,\n\nit was  the worst of times. ");
+        assert_eq!(text, "It was the best of times, it was the worst of times.");
+        assert_eq!(count_words(&text, TextLanguage::En), 12);
+    }
+
+    #[test]
+    fn difficulty_follows_word_length_and_symbols() {
+        assert_eq!(
+            estimate_difficulty(
+                "the cat sat on the mat and the dog ran to the sun",
+                TextLanguage::En
+            ),
+            TextDifficulty::Easy
+        );
+        assert_eq!(
+            estimate_difficulty(
+                "It is a truth universally acknowledged, that a single man in possession of a good fortune, must be in want of a wife.",
+                TextLanguage::En
+            ),
+            TextDifficulty::Medium
+        );
+        assert_eq!(
+            estimate_difficulty(
+                "Notwithstanding §4(b), the Licensee's indemnification obligations (USD 25,000) survive termination.",
+                TextLanguage::En
+            ),
+            TextDifficulty::Hard
+        );
+    }
+
+    #[test]
+    fn unspaced_languages_are_counted_by_characters() {
+        let text = "吾輩は猫である。名前はまだ無い。";
+        assert_eq!(count_words(text, TextLanguage::Ja), 7);
+        assert_eq!(
+            estimate_difficulty(text, TextLanguage::Ja),
+            TextDifficulty::Easy
+        );
+        assert_eq!(count_words("学而时习之，不亦说乎？", TextLanguage::Zh), 5);
+    }
+
+    #[test]
+    fn tags_are_lowercase_hyphenated_and_unique() {
+        let tags = normalise_tags(vec![
+            "Book Excerpt".into(),
+            "book-excerpt".into(),
+            " \"Classic\" ".into(),
+            "!!".into(),
+        ]);
+        assert_eq!(tags, serde_json::json!(["book-excerpt", "classic"]));
+    }
+}
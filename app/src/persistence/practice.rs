@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use models::domains::{tournaments, typing_history};
+use models::params::tournament::CreateTournamentParams;
+use models::schemas::typing::TypingSessionSchema;
+use sea_orm::{ActiveModelTrait, DbConn, DbErr, Set, TransactionTrait};
+
+use super::key_stats::record_key_stats;
+use super::text::GeneratedText;
+use super::tournament_texts::{TextStretch, record_tournament_texts};
+use super::tournaments::new_tournament;
+use crate::core::{KeyStats, session_duration_ms, session_outcome};
+
+pub const PRACTICE_TITLE: &str = "Practice";
+
+/// Saves a finished practice session of a registered member as an ended practice
+/// tournament with a single history entry, and adds its keystrokes to the member's
+/// key statistics. Practice has no opponents, so the entry is stored without a
+/// placement and never counts as a win.
+///
+/// Returns `false` for members without an account, who have nothing to attach
+/// the record to.
+pub async fn record_practice_session(
+    db: &DbConn,
+    session: &TypingSessionSchema,
+    text: &GeneratedText,
+    text_seed: i64,
+    key_stats: KeyStats,
+    ended_at: DateTime<Utc>,
+) -> Result<bool, DbErr> {
+    let Some(user_id) = session.member.user_id.clone() else {
+        return Ok(false);
+    };
+
+    let started_at = session.started_at.unwrap_or(ended_at).fixed_offset();
+    let params = CreateTournamentParams {
+        title: PRACTICE_TITLE.to_owned(),
+        description: String::new(),
+        scheduled_for: started_at,
+        text_options: None,
+        privacy: None,
+        race_duration_secs: None,
+        join_cutoff_secs: None,
+        inactivity_timeout_secs: None,
+        max_participants: None,
+        team_scoring: None,
+        rounds: None,
+        eliminations_per_round: None,
+        mode: None,
+        rematch_of: None,
+    };
+
+    let txn = db.begin().await?;
+
+    tournaments::ActiveModel {
+        id: Set(session.tournament_id.clone()),
+        scheduled_for: Set(started_at),
+        started_at: Set(Some(started_at)),
+        ended_at: Set(Some(ended_at.fixed_offset())),
+        invite_code: Set(None),
+        practice: Set(true),
+        text_seed: Set(Some(text_seed)),
+        ..new_tournament(params, user_id.clone())
+    }
+    .insert(&txn)
+    .await?;
+
+    typing_history::ActiveModel {
+        user_id: Set(user_id.clone()),
+        tournament_id: Set(session.tournament_id.clone()),
+        accuracy: Set(session.current_accuracy.round() as i32),
+        speed: Set(session.current_speed.round() as i32),
+        completed_at: Set(session.ended_at.unwrap_or(ended_at).fixed_offset()),
+        placement: Set(0),
+        duration_ms: Set(session_duration_ms(session)),
+        outcome: Set(session_outcome(session)),
+        anonymous: Set(session.member.user.is_none()),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    record_tournament_texts(
+        &txn,
+        &session.tournament_id,
+        vec![TextStretch {
+            round: 1,
+            offset: 0,
+            passage: text.passage,
+        }],
+    )
+    .await?;
+
+    record_key_stats(&txn, vec![(user_id, key_stats)]).await?;
+
+    txn.commit().await?;
+
+    Ok(true)
+}
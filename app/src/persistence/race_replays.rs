@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use models::domains::{race_replays, tournaments};
+use models::schemas::replay::{RaceReplaySchema, ReplayTrackSchema};
+use models::schemas::tournament::TournamentSchema;
+use models::schemas::user::{TournamentRoomMember, TournamentRoomUserProfile};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbConn, DbErr, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+
+use crate::core::{ReplayLog, decode_replay};
+
+use super::tournament_results::public_member_id;
+use super::tournament_texts::regenerate_tournament_texts;
+
+/// Tracks are stored under the same ids as the standings, see `aliases`.
+pub async fn record_race_replays<C: ConnectionTrait>(
+    db: &C,
+    tournament_id: &str,
+    replays: Vec<(TournamentRoomMember, ReplayLog)>,
+    aliases: &HashMap<String, String>,
+) -> Result<(), DbErr> {
+    if replays.is_empty() {
+        return Ok(());
+    }
+
+    let records = replays
+        .into_iter()
+        .map(|(member, log)| race_replays::ActiveModel {
+            tournament_id: Set(tournament_id.to_owned()),
+            member_id: Set(public_member_id(&member, aliases)),
+            user_id: Set(member.user_id),
+            username: Set(member.user.map(|u| u.username)),
+            started_at: Set(log.started_at().map(|v| v.fixed_offset())),
+            event_count: Set(log.event_count() as i32),
+            events: Set(log.into_bytes()),
+            ..Default::default()
+        });
+
+    race_replays::Entity::insert_many(records).exec(db).await?;
+
+    Ok(())
+}
+
+pub async fn get_race_replay(
+    db: &DbConn,
+    tournament_id: &str,
+) -> Result<Option<RaceReplaySchema>, DbErr> {
+    let tournament = match tournaments::Entity::find_by_id(tournament_id)
+        .one(db)
+        .await?
+    {
+        Some(tournament) => tournament,
+        None => return Ok(None),
+    };
+
+    let replays = race_replays::Entity::find()
+        .filter(race_replays::Column::TournamentId.eq(tournament_id))
+        .order_by_asc(race_replays::Column::Id)
+        .all(db)
+        .await?;
+
+    let tracks = replays
+        .into_iter()
+        .map(|replay| {
+            let events = decode_replay(&replay.events).ok_or_else(|| {
+                DbErr::Custom(format!("Corrupted replay for member {}", replay.member_id))
+            })?;
+
+            Ok(ReplayTrackSchema {
+                member: TournamentRoomMember {
+                    id: replay.member_id,
+                    user: replay
+                        .username
+                        .map(|username| TournamentRoomUserProfile { username }),
+                    participant: true,
+                    rating: None,
+                    user_id: None,
+                    team: None,
+                },
+                started_at: replay.started_at.map(|v| v.to_utc()),
+                events,
+            })
+        })
+        .collect::<Result<Vec<_>, DbErr>>()?;
+
+    // The last round's text is regenerated from the seed. Only tournaments
+    // created before seeds were stored still have it in `text`.
+    let text = match tournament.text_seed {
+        Some(_) => regenerate_tournament_texts(db, &TournamentSchema::from(tournament.clone()))
+            .await?
+            .pop()
+            .and_then(|round| round.text),
+        None => tournament.text.clone(),
+    };
+
+    Ok(Some(RaceReplaySchema {
+        tournament_id: tournament.id,
+        text,
+        started_at: tournament.started_at.map(|v| v.to_utc()),
+        tracks,
+    }))
+}
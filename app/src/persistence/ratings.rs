@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use models::domains::{rating_history, ratings};
+use models::schemas::rating::{RatingHistorySchema, UserRatingSchema};
+use models::schemas::typing::TypingSessionSchema;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbConn, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    Set,
+};
+
+use crate::core::{DEFAULT_RATING, rate_standings};
+
+pub const RATING_HISTORY_LIMIT: u64 = 50;
+
+/// Updates the rating of every registered participant from the final standings.
+///
+/// `standings` must already be ordered from first to last place. Guests are not rated and
+/// a race with fewer than two registered participants leaves ratings untouched.
+pub async fn record_ratings<C: ConnectionTrait>(
+    db: &C,
+    tournament_id: &str,
+    standings: &[TypingSessionSchema],
+    recorded_at: DateTime<Utc>,
+) -> Result<(), DbErr> {
+    let rated = standings
+        .iter()
+        .enumerate()
+        .filter_map(|(index, session)| {
+            let user_id = session.member.user_id.as_ref()?;
+            Some((user_id, index as i32 + 1, session.member.user.is_none()))
+        })
+        .collect::<Vec<_>>();
+
+    if rated.len() < 2 {
+        return Ok(());
+    }
+
+    let current = ratings::Entity::find()
+        .filter(ratings::Column::UserId.is_in(rated.iter().map(|(user_id, ..)| user_id.as_str())))
+        .lock_exclusive()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|rating| (rating.user_id, rating.rating))
+        .collect::<HashMap<_, _>>();
+
+    let before = rated
+        .iter()
+        .map(|(user_id, ..)| current.get(*user_id).copied().unwrap_or(DEFAULT_RATING))
+        .collect::<Vec<_>>();
+    let after = rate_standings(&before);
+
+    let updated_at = recorded_at.fixed_offset();
+
+    let records = rated
+        .iter()
+        .zip(&after)
+        .map(|((user_id, ..), &rating)| ratings::ActiveModel {
+            user_id: Set((*user_id).clone()),
+            rating: Set(rating),
+            games: Set(1),
+            updated_at: Set(updated_at),
+        });
+
+    ratings::Entity::insert_many(records)
+        .on_conflict(
+            OnConflict::column(ratings::Column::UserId)
+                .update_columns([ratings::Column::Rating, ratings::Column::UpdatedAt])
+                .value(
+                    ratings::Column::Games,
+                    Expr::col((ratings::Entity, ratings::Column::Games)).add(1),
+                )
+                .to_owned(),
+        )
+        .exec(db)
+        .await?;
+
+    let history = rated.iter().zip(before.iter().zip(&after)).map(
+        |((user_id, placement, anonymous), (&rating_before, &rating_after))| {
+            rating_history::ActiveModel {
+                user_id: Set((*user_id).clone()),
+                tournament_id: Set(tournament_id.to_owned()),
+                rating_before: Set(rating_before),
+                rating_after: Set(rating_after),
+                placement: Set(*placement),
+                anonymous: Set(*anonymous),
+                recorded_at: Set(updated_at),
+                ..Default::default()
+            }
+        },
+    );
+
+    rating_history::Entity::insert_many(history)
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_rating(db: &DbConn, user_id: &str) -> Result<i32, DbErr> {
+    Ok(ratings::Entity::find_by_id(user_id)
+        .one(db)
+        .await?
+        .map_or(DEFAULT_RATING, |rating| rating.rating))
+}
+
+/// Anonymous races still move the rating, but only show up in the history when
+/// `include_anonymous` is set, matching the typing history listing.
+pub async fn get_user_rating(
+    db: &DbConn,
+    user_id: &str,
+    include_anonymous: bool,
+) -> Result<UserRatingSchema, DbErr> {
+    let rating = ratings::Entity::find_by_id(user_id).one(db).await?;
+
+    let mut sql_query =
+        rating_history::Entity::find().filter(rating_history::Column::UserId.eq(user_id));
+
+    if !include_anonymous {
+        sql_query = sql_query.filter(rating_history::Column::Anonymous.eq(false));
+    }
+
+    let history = sql_query
+        .order_by_desc(rating_history::Column::RecordedAt)
+        .order_by_desc(rating_history::Column::Id)
+        .limit(RATING_HISTORY_LIMIT)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(RatingHistorySchema::from)
+        .collect();
+
+    Ok(UserRatingSchema {
+        user_id: user_id.to_owned(),
+        rating: rating.as_ref().map_or(DEFAULT_RATING, |r| r.rating),
+        games: rating.as_ref().map_or(0, |r| r.games),
+        history,
+    })
+}
@@ -0,0 +1,289 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+use models::domains::sea_orm_active_enums::RaceOutcome;
+use models::domains::{tournament_series, tournaments, typing_history, users};
+use models::params::series::CreateSeriesParams;
+use models::params::tournament::{
+    CreateTournamentParams, DEFAULT_INACTIVITY_TIMEOUT_SECS, DEFAULT_JOIN_CUTOFF_SECS,
+    DEFAULT_RACE_DURATION_SECS,
+};
+use models::schemas::series::{
+    RecurrenceRule, SeriesResultsSchema, SeriesSchema, SeriesStandingSchema,
+};
+use models::schemas::tournament::TournamentSchema;
+use models::schemas::typing::TextOptions;
+use sea_orm::ActiveValue::Unchanged;
+use sea_orm::sea_query::{Alias, Expr, Func, OnConflict, SimpleExpr};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DbConn, DbErr, EntityTrait, FromQueryResult, JoinType, Order,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set,
+};
+
+use super::tournaments::new_tournament;
+use crate::core::occurrences;
+
+/// How often active series are topped up with new tournaments.
+pub const SERIES_MATERIALISE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// How far ahead tournaments are materialised.
+const MATERIALISE_HORIZON: TimeDelta = TimeDelta::days(7);
+/// Occurrences closer than this are skipped, matching the earliest start
+/// `create_tournament` accepts.
+const MATERIALISE_LEAD: TimeDelta = TimeDelta::seconds(30);
+
+#[derive(FromQueryResult)]
+struct SeriesStandingRow {
+    user_id: String,
+    username: String,
+    races: i64,
+    wins: i64,
+    best_speed: i32,
+    average_speed: f64,
+    average_accuracy: f64,
+}
+
+pub async fn create_series(
+    db: &DbConn,
+    params: CreateSeriesParams,
+    user_id: &str,
+) -> Result<tournament_series::Model, DbErr> {
+    let id_len = super::TOURNAMENT_ID_LENGTH;
+    let id = nanoid::nanoid!(id_len, &super::ID_ALPHABET);
+
+    let series = tournament_series::ActiveModel {
+        id: Set(id),
+        title: Set(params.title),
+        description: Set(params.description),
+        created_by: Set(user_id.to_owned()),
+        recurrence: Set(params.recurrence.to_value()),
+        text_options: Set(params.text_options.map(TextOptions::to_value)),
+        race_duration_secs: Set(params
+            .race_duration_secs
+            .unwrap_or(DEFAULT_RACE_DURATION_SECS)),
+        join_cutoff_secs: Set(params.join_cutoff_secs.unwrap_or(DEFAULT_JOIN_CUTOFF_SECS)),
+        inactivity_timeout_secs: Set(params
+            .inactivity_timeout_secs
+            .unwrap_or(DEFAULT_INACTIVITY_TIMEOUT_SECS)),
+        active: Set(true),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+
+    materialise_series(db, &series, Utc::now()).await?;
+
+    Ok(series)
+}
+
+pub async fn get_series(db: &DbConn, id: &str) -> Result<Option<SeriesSchema>, DbErr> {
+    let Some(series) = tournament_series::Entity::find_by_id(id).one(db).await? else {
+        return Ok(None);
+    };
+
+    let upcoming = tournaments::Entity::find()
+        .filter(tournaments::Column::SeriesId.eq(id))
+        .filter(tournaments::Column::EndedAt.is_null())
+        .order_by_asc(tournaments::Column::ScheduledFor)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(TournamentSchema::from)
+        .collect();
+
+    Ok(Some(SeriesSchema::new(series, upcoming)))
+}
+
+/// Stops materialising new tournaments. Already materialised ones are kept and
+/// can be cancelled one by one.
+pub async fn deactivate_series(db: &DbConn, id: &str) -> Result<(), DbErr> {
+    tournament_series::ActiveModel {
+        id: Unchanged(id.to_owned()),
+        active: Set(false),
+        ..Default::default()
+    }
+    .update(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Inserts the series' tournaments scheduled within the materialisation horizon
+/// that do not exist yet. Returns how many were scheduled.
+pub async fn materialise_series(
+    db: &DbConn,
+    series: &tournament_series::Model,
+    now: DateTime<Utc>,
+) -> Result<usize, DbErr> {
+    if !series.active {
+        return Ok(0);
+    }
+
+    let from = now + MATERIALISE_LEAD;
+    let rule = RecurrenceRule::from_value(series.recurrence.clone());
+
+    let existing = tournaments::Entity::find()
+        .select_only()
+        .column(tournaments::Column::ScheduledFor)
+        .filter(tournaments::Column::SeriesId.eq(&series.id))
+        .filter(tournaments::Column::ScheduledFor.gte(from))
+        .into_tuple::<DateTime<FixedOffset>>()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|scheduled_for| scheduled_for.to_utc())
+        .collect::<HashSet<_>>();
+
+    let records = occurrences(&rule, from, now + MATERIALISE_HORIZON)
+        .into_iter()
+        .filter(|scheduled_for| !existing.contains(scheduled_for))
+        .map(|scheduled_for| {
+            let params = CreateTournamentParams {
+                title: series.title.clone(),
+                description: series.description.clone(),
+                scheduled_for: scheduled_for.fixed_offset(),
+                text_options: series.text_options.clone().map(TextOptions::from_value),
+                privacy: None,
+                race_duration_secs: Some(series.race_duration_secs),
+                join_cutoff_secs: Some(series.join_cutoff_secs),
+                inactivity_timeout_secs: Some(series.inactivity_timeout_secs),
+                max_participants: None,
+                team_scoring: None,
+                rounds: None,
+                eliminations_per_round: None,
+                mode: None,
+                rematch_of: None,
+            };
+
+            tournaments::ActiveModel {
+                series_id: Set(Some(series.id.clone())),
+                ..new_tournament(params, series.created_by.clone())
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let created = records.len();
+    if created == 0 {
+        return Ok(0);
+    }
+
+    tournaments::Entity::insert_many(records)
+        .on_conflict(
+            OnConflict::columns([
+                tournaments::Column::SeriesId,
+                tournaments::Column::ScheduledFor,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .do_nothing()
+        .exec(db)
+        .await?;
+
+    Ok(created)
+}
+
+pub async fn materialise_active_series(db: &DbConn, now: DateTime<Utc>) -> Result<usize, DbErr> {
+    let mut created = 0;
+
+    for series in tournament_series::Entity::find()
+        .filter(tournament_series::Column::Active.eq(true))
+        .all(db)
+        .await?
+    {
+        created += materialise_series(db, &series, now).await?;
+    }
+
+    Ok(created)
+}
+
+/// Aggregates the registered members' `typing_history` over every tournament of
+/// the series, ranked by wins and then by average speed.
+pub async fn get_series_results(
+    db: &DbConn,
+    series_id: &str,
+) -> Result<Option<SeriesResultsSchema>, DbErr> {
+    if tournament_series::Entity::find_by_id(series_id)
+        .one(db)
+        .await?
+        .is_none()
+    {
+        return Ok(None);
+    }
+
+    let ended_tournaments = tournaments::Entity::find()
+        .filter(tournaments::Column::SeriesId.eq(series_id))
+        .filter(tournaments::Column::Practice.eq(false))
+        .filter(tournaments::Column::EndedAt.is_not_null())
+        .count(db)
+        .await?;
+
+    let win: SimpleExpr = Expr::case(
+        Expr::col((typing_history::Entity, typing_history::Column::Placement))
+            .eq(1)
+            .and(
+                Expr::col((typing_history::Entity, typing_history::Column::Outcome))
+                    .eq(RaceOutcome::Finished),
+            ),
+        1,
+    )
+    .finally(0)
+    .into();
+    let average = |column: typing_history::Column| {
+        SimpleExpr::from(Func::avg(Expr::col((typing_history::Entity, column))))
+            .cast_as(Alias::new("double precision"))
+    };
+
+    let rows = typing_history::Entity::find()
+        .select_only()
+        .column(typing_history::Column::UserId)
+        .column_as(users::Column::Username, "username")
+        .column_as(typing_history::Column::Id.count(), "races")
+        .column_as(
+            SimpleExpr::from(Func::sum(win)).cast_as(Alias::new("bigint")),
+            "wins",
+        )
+        .column_as(typing_history::Column::Speed.max(), "best_speed")
+        .column_as(average(typing_history::Column::Speed), "average_speed")
+        .column_as(
+            average(typing_history::Column::Accuracy),
+            "average_accuracy",
+        )
+        .join(JoinType::InnerJoin, typing_history::Relation::Users.def())
+        .join(
+            JoinType::InnerJoin,
+            typing_history::Relation::Tournaments.def(),
+        )
+        .filter(tournaments::Column::SeriesId.eq(series_id))
+        .filter(tournaments::Column::Practice.eq(false))
+        .filter(typing_history::Column::Anonymous.eq(false))
+        .group_by(typing_history::Column::UserId)
+        .group_by(users::Column::Username)
+        .order_by(Expr::col(Alias::new("wins")), Order::Desc)
+        .order_by(Expr::col(Alias::new("average_speed")), Order::Desc)
+        .order_by_asc(typing_history::Column::UserId)
+        .into_model::<SeriesStandingRow>()
+        .all(db)
+        .await?;
+
+    let standings = rows
+        .into_iter()
+        .enumerate()
+        .map(|(index, row)| SeriesStandingSchema {
+            rank: index + 1,
+            user_id: row.user_id,
+            username: row.username,
+            races: row.races,
+            wins: row.wins,
+            best_speed: row.best_speed,
+            average_speed: row.average_speed as f32,
+            average_accuracy: row.average_accuracy as f32,
+        })
+        .collect();
+
+    Ok(Some(SeriesResultsSchema {
+        series_id: series_id.to_owned(),
+        tournaments: ended_tournaments,
+        standings,
+    }))
+}
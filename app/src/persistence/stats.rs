@@ -0,0 +1,200 @@
+use models::domains::{sea_orm_active_enums::RaceOutcome, tournaments, typing_history};
+use models::schemas::stats::{SpeedPercentiles, SpeedTrend, UserStatsSchema};
+use sea_orm::sea_query::{Alias, Expr, Func, SimpleExpr};
+use sea_orm::{
+    ColumnTrait, DbConn, DbErr, EntityTrait, FromQueryResult, JoinType, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait, Select,
+};
+
+pub const DEFAULT_TREND_RACES: usize = 10;
+pub const MAX_TREND_RACES: usize = 100;
+
+#[derive(FromQueryResult)]
+struct FinishedRow {
+    races: i64,
+    wins: Option<i64>,
+    average_speed: Option<f64>,
+    average_accuracy: Option<f64>,
+    best_speed: Option<i32>,
+}
+
+/// Aggregates a user's typing history into profile statistics.
+///
+/// Anonymous races only count when `include_anonymous` is set, matching the history listing.
+/// Practice runs never count, they have no opponents to enter against or beat.
+/// Speed figures only consider finished races; a timed out or abandoned race would
+/// otherwise drag every average towards zero.
+pub async fn get_user_stats(
+    db: &DbConn,
+    user_id: &str,
+    recent: usize,
+    include_anonymous: bool,
+) -> Result<UserStatsSchema, DbErr> {
+    let races_entered = user_races(user_id, include_anonymous).count(db).await?;
+
+    let finished = user_races(user_id, include_anonymous)
+        .filter(typing_history::Column::Outcome.eq(RaceOutcome::Finished));
+
+    let row = finished
+        .clone()
+        .select_only()
+        .column_as(typing_history::Column::Id.count(), "races")
+        .column_as(
+            SimpleExpr::from(Func::sum(
+                Expr::case(typing_history::Column::Placement.eq(1), 1).finally(0),
+            )),
+            "wins",
+        )
+        .column_as(
+            as_double(Func::avg(Expr::col(typing_history::Column::Speed)).into()),
+            "average_speed",
+        )
+        .column_as(
+            as_double(Func::avg(Expr::col(typing_history::Column::Accuracy)).into()),
+            "average_accuracy",
+        )
+        .column_as(typing_history::Column::Speed.max(), "best_speed")
+        .into_model::<FinishedRow>()
+        .one(db)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("User statistics".to_owned()))?;
+
+    let races_completed = row.races as u64;
+    let wins = row.wins.unwrap_or(0) as u64;
+
+    let speed_percentiles = if races_completed > 0 {
+        Some(SpeedPercentiles {
+            p25: speed_at(db, &finished, nearest_rank(races_completed, 25)).await?,
+            p50: speed_at(db, &finished, nearest_rank(races_completed, 50)).await?,
+            p75: speed_at(db, &finished, nearest_rank(races_completed, 75)).await?,
+            p90: speed_at(db, &finished, nearest_rank(races_completed, 90)).await?,
+        })
+    } else {
+        None
+    };
+
+    let mut recent_speeds = finished
+        .select_only()
+        .column(typing_history::Column::Speed)
+        .order_by_desc(typing_history::Column::CompletedAt)
+        .order_by_desc(typing_history::Column::Id)
+        .limit(recent.clamp(1, MAX_TREND_RACES) as u64)
+        .into_tuple::<i32>()
+        .all(db)
+        .await?;
+    recent_speeds.reverse();
+
+    Ok(UserStatsSchema {
+        user_id: user_id.to_owned(),
+        races_entered,
+        races_completed,
+        wins,
+        win_rate: if races_entered > 0 {
+            wins as f32 / races_entered as f32
+        } else {
+            0.0
+        },
+        average_speed: row.average_speed.unwrap_or(0.0) as f32,
+        best_speed: row.best_speed.unwrap_or(0),
+        average_accuracy: row.average_accuracy.unwrap_or(0.0) as f32,
+        speed_percentiles,
+        trend: SpeedTrend {
+            average_speed: average(&recent_speeds),
+            slope: slope(&recent_speeds),
+            speeds: recent_speeds,
+        },
+    })
+}
+
+fn user_races(user_id: &str, include_anonymous: bool) -> Select<typing_history::Entity> {
+    let mut sql_query = typing_history::Entity::find()
+        .join(
+            JoinType::InnerJoin,
+            typing_history::Relation::Tournaments.def(),
+        )
+        .filter(tournaments::Column::Practice.eq(false))
+        .filter(typing_history::Column::UserId.eq(user_id));
+
+    if !include_anonymous {
+        sql_query = sql_query.filter(typing_history::Column::Anonymous.eq(false));
+    }
+
+    sql_query
+}
+
+/// The `rank`th (1-based) slowest speed among `races`.
+async fn speed_at(
+    db: &DbConn,
+    races: &Select<typing_history::Entity>,
+    rank: u64,
+) -> Result<i32, DbErr> {
+    let speed = races
+        .clone()
+        .select_only()
+        .column(typing_history::Column::Speed)
+        .order_by_asc(typing_history::Column::Speed)
+        .offset(rank - 1)
+        .limit(1)
+        .into_tuple::<i32>()
+        .one(db)
+        .await?;
+
+    Ok(speed.unwrap_or(0))
+}
+
+fn as_double(expr: SimpleExpr) -> SimpleExpr {
+    expr.cast_as(Alias::new("double precision"))
+}
+
+fn average(values: &[i32]) -> f32 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().map(|&v| v as f64).sum::<f64>() as f32 / values.len() as f32
+}
+
+/// 1-based nearest rank of the `p`th percentile among `count` ascending values.
+fn nearest_rank(count: u64, p: u64) -> u64 {
+    (p * count).div_ceil(100).max(1)
+}
+
+fn slope(values: &[i32]) -> f32 {
+    let n = values.len() as f64;
+    if values.len() < 2 {
+        return 0.0;
+    }
+
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = values.iter().map(|&v| v as f64).sum::<f64>() / n;
+
+    let (numerator, denominator) =
+        values
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(numerator, denominator), (x, &y)| {
+                let dx = x as f64 - mean_x;
+                (numerator + dx * (y as f64 - mean_y), denominator + dx * dx)
+            });
+
+    (numerator / denominator) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        assert_eq!(nearest_rank(10, 25), 3);
+        assert_eq!(nearest_rank(10, 50), 5);
+        assert_eq!(nearest_rank(10, 90), 9);
+        assert_eq!(nearest_rank(1, 90), 1);
+    }
+
+    #[test]
+    fn slope_follows_improvement() {
+        assert_eq!(slope(&[50]), 0.0);
+        assert!((slope(&[50, 55, 60, 65]) - 5.0).abs() < f32::EPSILON);
+        assert!(slope(&[80, 70, 60]) < 0.0);
+    }
+}
@@ -1,11 +1,371 @@
-use models::schemas::typing::TextOptions;
+use models::domains::sea_orm_active_enums::TextLanguage;
+use models::schemas::typing::{DEFAULT_WORD_COUNT, MAX_WORD_COUNT, MIN_WORD_COUNT, TextOptions};
+use rand::{Rng, SeedableRng, seq::IndexedRandom};
+use rand_chacha::ChaCha8Rng;
 use random_word::Lang;
+use sea_orm::{DbConn, DbErr};
+use tracing::warn;
 
-pub fn generate_text(_options: TextOptions) -> String {
-    // Generate a random text based on the provided options in the future
-    let text = (0..24)
-        .map(|_| random_word::get(Lang::En))
-        .collect::<Vec<_>>()
-        .join(" ");
-    text
+use super::passages::{get_passage, normalise_passage_text, race_passage_ids};
+
+/// Share of words replaced by a number when `numbers` is set alongside letters.
+const NUMBER_RATIO: f64 = 0.15;
+/// Share of words capitalised mid-sentence when both letter cases are allowed.
+const CAPITALISED_RATIO: f64 = 0.1;
+/// Chances that a word ends its sentence, or is followed by a pause, when `symbols` is set.
+const SENTENCE_END_RATIO: f64 = 0.12;
+const PAUSE_RATIO: f64 = 0.1;
+const QUOTE_RATIO: f64 = 0.03;
+
+const SENTENCE_ENDS: [&str; 5] = [".", ".", ".", "!", "?"];
+const PAUSES: [&str; 4] = [",", ",", ";", ":"];
+const QUOTES: [(&str, &str); 2] = [("\"", "\""), ("(", ")")];
+
+/// The revision of a passage a text was drawn from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PassageRevision {
+    pub id: i32,
+    pub revision: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedText {
+    pub text: String,
+    /// Set when the text was drawn from a passage rather than generated.
+    pub passage: Option<PassageRevision>,
+}
+
+/// Meaningful texts are drawn from the approved passages when one fits the length
+/// and difficulty asked for, and made of dictionary words otherwise.
+///
+/// Which passage is drawn depends on the approved passages at the time, so it is
+/// returned to be stored. Given it, `regenerate_text` gives the text again.
+pub async fn generate_text(db: &DbConn, options: TextOptions, seed: u64) -> GeneratedText {
+    if options.meaningful && (options.uppercase || options.lowercase) {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        match draw_passage(db, options, &mut rng).await {
+            Ok(Some((text, passage))) => {
+                return GeneratedText {
+                    text,
+                    passage: Some(passage),
+                };
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Failed to draw a passage, using words instead: {}", e),
+        }
+    }
+
+    GeneratedText {
+        text: generate_text_with(options, &mut ChaCha8Rng::seed_from_u64(seed)),
+        passage: None,
+    }
+}
+
+/// The text `generate_text` gave for `seed` and `options`, drawn from `passage`
+/// if it was. `None` once that passage has been edited or removed.
+pub async fn regenerate_text(
+    db: &DbConn,
+    options: TextOptions,
+    seed: u64,
+    passage: Option<PassageRevision>,
+) -> Result<Option<String>, DbErr> {
+    let Some(drawn) = passage else {
+        return Ok(Some(generate_text_with(
+            options,
+            &mut ChaCha8Rng::seed_from_u64(seed),
+        )));
+    };
+
+    Ok(get_passage(db, drawn.id)
+        .await?
+        .filter(|passage| passage.revision == drawn.revision)
+        .map(|passage| fit_passage(&passage.text, options)))
+}
+
+/// The seed of one text of a tournament: round `round`, or the stretch of a
+/// time-attack text starting `offset` characters in.
+pub fn race_seed(text_seed: i64, round: i32, offset: usize) -> u64 {
+    (text_seed as u64)
+        ^ (round as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (offset as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F)
+}
+
+fn word_count(options: TextOptions) -> u32 {
+    options
+        .word_count
+        .unwrap_or(DEFAULT_WORD_COUNT)
+        .clamp(MIN_WORD_COUNT, MAX_WORD_COUNT)
+}
+
+async fn draw_passage(
+    db: &DbConn,
+    options: TextOptions,
+    rng: &mut impl Rng,
+) -> Result<Option<(String, PassageRevision)>, DbErr> {
+    let ids = race_passage_ids(
+        db,
+        word_count(options),
+        options.difficulty,
+        options.language,
+    )
+    .await?;
+    let Some(&id) = ids.choose(rng) else {
+        return Ok(None);
+    };
+
+    Ok(get_passage(db, id)
+        .await?
+        .map(|passage| {
+            let drawn = PassageRevision {
+                id: passage.id,
+                revision: passage.revision,
+            };
+            (fit_passage(&passage.text, options), drawn)
+        })
+        .filter(|(text, _)| !text.is_empty()))
+}
+
+/// Passages keep their wording, but drop the characters and letter case the
+/// options rule out.
+fn fit_passage(text: &str, options: TextOptions) -> String {
+    let text: String = text
+        .chars()
+        .filter(|c| {
+            c.is_whitespace()
+                || c.is_alphabetic()
+                || if c.is_numeric() {
+                    options.numbers
+                } else {
+                    options.symbols
+                }
+        })
+        .collect();
+    let text = normalise_passage_text(&text);
+
+    match (options.uppercase, options.lowercase) {
+        (true, false) => text.to_uppercase(),
+        (false, true) => text.to_lowercase(),
+        _ => text,
+    }
+}
+
+fn generate_text_with(options: TextOptions, rng: &mut impl Rng) -> String {
+    let word_count = word_count(options) as usize;
+    let letters = options.uppercase || options.lowercase;
+
+    let mut sentence_start = true;
+    let mut words = Vec::with_capacity(word_count);
+
+    for index in 0..word_count {
+        let mut word = if options.numbers && (!letters || rng.random_bool(NUMBER_RATIO)) {
+            number(rng)
+        } else {
+            let word = if options.meaningful {
+                dictionary_word(options.language, rng)
+            } else {
+                random_letters(options.language, rng)
+            };
+            apply_case(word, options, sentence_start, rng)
+        };
+        sentence_start = false;
+
+        if options.symbols {
+            if rng.random_bool(QUOTE_RATIO) {
+                let (open, close) = QUOTES.choose(rng).unwrap();
+                word = format!("{open}{word}{close}");
+            }
+
+            if index + 1 == word_count || rng.random_bool(SENTENCE_END_RATIO) {
+                word.push_str(SENTENCE_ENDS.choose(rng).unwrap());
+                sentence_start = true;
+            } else if rng.random_bool(PAUSE_RATIO) {
+                word.push_str(PAUSES.choose(rng).unwrap());
+            }
+        }
+
+        words.push(word);
+    }
+
+    words.join(" ")
+}
+
+fn dictionary(language: TextLanguage) -> Lang {
+    match language {
+        TextLanguage::En => Lang::En,
+        TextLanguage::De => Lang::De,
+        TextLanguage::Es => Lang::Es,
+        TextLanguage::Fr => Lang::Fr,
+        TextLanguage::Ja => Lang::Ja,
+        TextLanguage::Zh => Lang::Zh,
+    }
+}
+
+/// A lowercase dictionary word, stripped of anything but letters.
+fn dictionary_word(language: TextLanguage, rng: &mut impl Rng) -> String {
+    let word: String = random_word::all(dictionary(language))
+        .choose(rng)
+        .map(|word| word.chars().filter(|c| c.is_alphabetic()).collect())
+        .unwrap_or_default();
+
+    if word.is_empty() {
+        return random_letters(language, rng);
+    }
+    word.to_lowercase()
+}
+
+/// The letters random texts are made of: the alphabet of each language,
+/// hiragana for Japanese and the most common characters for Chinese.
+fn alphabet(language: TextLanguage) -> &'static str {
+    match language {
+        TextLanguage::En => "abcdefghijklmnopqrstuvwxyz",
+        TextLanguage::De => "abcdefghijklmnopqrstuvwxyzäöüß",
+        TextLanguage::Es => "abcdefghijklmnopqrstuvwxyzáéíñóúü",
+        TextLanguage::Fr => "abcdefghijklmnopqrstuvwxyzàâçéèêëîïôùûü",
+        TextLanguage::Ja => {
+            "あいうえおかきくけこさしすせそたちつてとなにぬねのはひふへほまみむめもやゆよらりるれろわをん\
+             がぎぐげござじずぜぞだぢづでどばびぶべぼぱぴぷぺぽ"
+        }
+        TextLanguage::Zh => {
+            "的一是不了人我在有他这中大来上个国到说们为子和你地出道也时年得就那要下以生会自着去之过家学对\
+             可里后小么心多天而能好都然没日于起还发成事只作当想看文无开手十用主行方又如前所本见经头面公同"
+        }
+    }
+}
+
+/// A lowercase run of 2 to 8 random letters of `language`.
+fn random_letters(language: TextLanguage, rng: &mut impl Rng) -> String {
+    let letters: Vec<char> = alphabet(language).chars().collect();
+    let len = rng.random_range(2..=8);
+    (0..len).filter_map(|_| letters.choose(rng)).collect()
+}
+
+fn number(rng: &mut impl Rng) -> String {
+    if rng.random_bool(0.25) {
+        rng.random_range(1900..=2030).to_string()
+    } else {
+        rng.random_range(0..1000).to_string()
+    }
+}
+
+/// Texts allowing both cases capitalise the start of each sentence and the odd
+/// word in between. With neither case allowed, words stay lowercase.
+fn apply_case(
+    word: String,
+    options: TextOptions,
+    sentence_start: bool,
+    rng: &mut impl Rng,
+) -> String {
+    match (options.uppercase, options.lowercase) {
+        (true, false) => word.to_uppercase(),
+        (true, true) if sentence_start || rng.random_bool(CAPITALISED_RATIO) => {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => word,
+            }
+        }
+        _ => word,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(uppercase: bool, lowercase: bool, numbers: bool, symbols: bool) -> TextOptions {
+        TextOptions {
+            uppercase,
+            lowercase,
+            numbers,
+            symbols,
+            meaningful: false,
+            word_count: Some(60),
+            difficulty: None,
+            language: TextLanguage::En,
+        }
+    }
+
+    fn generate(options: TextOptions) -> String {
+        generate_text_with(options, &mut ChaCha8Rng::seed_from_u64(7))
+    }
+
+    #[test]
+    fn honours_the_word_count() {
+        let text = generate(TextOptions {
+            word_count: Some(42),
+            ..TextOptions::default()
+        });
+        assert_eq!(text.split(' ').count(), 42);
+
+        let text = generate(TextOptions {
+            word_count: Some(10_000),
+            ..TextOptions::default()
+        });
+        assert_eq!(text.split(' ').count(), MAX_WORD_COUNT as usize);
+    }
+
+    #[test]
+    fn plain_options_give_plain_words() {
+        let text = generate(options(false, true, false, false));
+        assert!(text.chars().all(|c| c == ' ' || c.is_ascii_lowercase()));
+
+        let text = generate(options(true, false, false, false));
+        assert!(text.chars().all(|c| c == ' ' || c.is_ascii_uppercase()));
+    }
+
+    #[test]
+    fn random_letters_follow_the_language() {
+        let text = generate(TextOptions {
+            language: TextLanguage::Ja,
+            ..options(false, true, false, false)
+        });
+        assert!(
+            text.chars()
+                .all(|c| c == ' ' || alphabet(TextLanguage::Ja).contains(c))
+        );
+    }
+
+    #[test]
+    fn numbers_without_letters_give_numerals_only() {
+        let text = generate(options(false, false, true, false));
+        assert!(text.chars().all(|c| c == ' ' || c.is_ascii_digit()));
+    }
+
+    #[test]
+    fn symbols_end_the_text_with_punctuation() {
+        let text = generate(options(true, true, true, true));
+        assert!(text.ends_with(['.', '!', '?']));
+        assert!(
+            text.starts_with(|c: char| c.is_uppercase()
+                || c.is_ascii_digit()
+                || c == '"'
+                || c == '(')
+        );
+        assert!(text.chars().any(|c| c.is_ascii_digit()));
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_text() {
+        let options = options(true, true, true, true);
+        let text = |seed| generate_text_with(options, &mut ChaCha8Rng::seed_from_u64(seed));
+
+        assert_eq!(text(race_seed(42, 1, 0)), text(race_seed(42, 1, 0)));
+        assert_ne!(text(race_seed(42, 1, 0)), text(race_seed(42, 2, 0)));
+        assert_ne!(text(race_seed(42, 1, 0)), text(race_seed(42, 1, 300)));
+        assert_ne!(text(race_seed(42, 1, 0)), text(race_seed(43, 1, 0)));
+    }
+
+    #[test]
+    fn passages_drop_what_the_options_rule_out() {
+        let passage = "\"Call me Ishmael.\" Some years ago - never mind how long precisely...";
+
+        assert_eq!(
+            fit_passage(passage, options(false, true, false, false)),
+            "call me ishmael some years ago never mind how long precisely"
+        );
+        assert_eq!(
+            fit_passage(passage, options(true, true, true, true)),
+            passage
+        );
+    }
 }
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use models::domains::{tournament_bans, tournaments};
+use models::schemas::ban::{BannedMemberSchema, TournamentBansSchema};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ColumnTrait, DbConn, DbErr, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set,
+};
+
+pub async fn get_tournament_bans(
+    db: &DbConn,
+    tournament_id: &str,
+) -> Result<Option<TournamentBansSchema>, DbErr> {
+    let Some(tournament) = tournaments::Entity::find_by_id(tournament_id)
+        .one(db)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let bans = tournament_bans::Entity::find()
+        .filter(tournament_bans::Column::TournamentId.eq(tournament_id))
+        .order_by_asc(tournament_bans::Column::BannedAt)
+        .order_by_asc(tournament_bans::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|ban| BannedMemberSchema {
+            member_id: ban.member_id,
+            banned_at: ban.banned_at.to_utc(),
+        })
+        .collect();
+
+    Ok(Some(TournamentBansSchema {
+        tournament_id: tournament.id,
+        bans,
+    }))
+}
+
+/// Bans the member from the tournament. Banning a member twice keeps the first ban.
+pub async fn ban_member(
+    db: &DbConn,
+    tournament_id: &str,
+    member_id: &str,
+    banned_at: DateTime<Utc>,
+) -> Result<(), DbErr> {
+    let record = tournament_bans::ActiveModel {
+        tournament_id: Set(tournament_id.to_owned()),
+        member_id: Set(member_id.to_owned()),
+        banned_at: Set(banned_at.fixed_offset()),
+        ..Default::default()
+    };
+
+    tournament_bans::Entity::insert(record)
+        .on_conflict(
+            OnConflict::columns([
+                tournament_bans::Column::TournamentId,
+                tournament_bans::Column::MemberId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .do_nothing()
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn lift_ban(db: &DbConn, tournament_id: &str, member_id: &str) -> Result<bool, DbErr> {
+    let result = tournament_bans::Entity::delete_many()
+        .filter(tournament_bans::Column::TournamentId.eq(tournament_id))
+        .filter(tournament_bans::Column::MemberId.eq(member_id))
+        .exec(db)
+        .await?;
+
+    Ok(result.rows_affected > 0)
+}
+
+pub async fn is_banned(db: &DbConn, tournament_id: &str, member_id: &str) -> Result<bool, DbErr> {
+    let bans = tournament_bans::Entity::find()
+        .filter(tournament_bans::Column::TournamentId.eq(tournament_id))
+        .filter(tournament_bans::Column::MemberId.eq(member_id))
+        .count(db)
+        .await?;
+
+    Ok(bans > 0)
+}
@@ -0,0 +1,199 @@
+use chrono::{DateTime, Utc};
+use models::domains::sea_orm_active_enums::TournamentPrivacy;
+use models::domains::{tournament_invites, tournaments, users};
+use models::schemas::invite::{InviteeSchema, TournamentInvitesSchema};
+use sea_orm::ActiveValue::Unchanged;
+use sea_orm::sea_query::{OnConflict, Query};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DbConn, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set,
+};
+
+pub fn generate_invite_code() -> String {
+    let code_len = super::INVITE_CODE_LENGTH;
+    nanoid::nanoid!(code_len, &super::ID_ALPHABET)
+}
+
+pub async fn get_tournament_invites(
+    db: &DbConn,
+    tournament_id: &str,
+) -> Result<Option<TournamentInvitesSchema>, DbErr> {
+    let Some(tournament) = tournaments::Entity::find_by_id(tournament_id)
+        .one(db)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let invitees = tournament_invites::Entity::find()
+        .filter(tournament_invites::Column::TournamentId.eq(tournament_id))
+        .order_by_asc(tournament_invites::Column::InvitedAt)
+        .order_by_asc(tournament_invites::Column::Id)
+        .find_also_related(users::Entity)
+        .all(db)
+        .await?
+        .into_iter()
+        .filter_map(|(invite, user)| {
+            Some(InviteeSchema {
+                user_id: invite.user_id,
+                username: user?.username,
+                invited_at: invite.invited_at.to_utc(),
+            })
+        })
+        .collect();
+
+    Ok(Some(TournamentInvitesSchema {
+        tournament_id: tournament.id,
+        invite_code: tournament.invite_code,
+        invitees,
+    }))
+}
+
+/// Adds the given users to the invite list. Unknown user ids and users that are
+/// already invited are skipped.
+pub async fn invite_users(
+    db: &DbConn,
+    tournament_id: &str,
+    user_ids: Vec<String>,
+    invited_at: DateTime<Utc>,
+) -> Result<(), DbErr> {
+    let existing = users::Entity::find()
+        .select_only()
+        .column(users::Column::Id)
+        .filter(users::Column::Id.is_in(user_ids))
+        .into_tuple::<String>()
+        .all(db)
+        .await?;
+
+    if existing.is_empty() {
+        return Ok(());
+    }
+
+    let records = existing
+        .into_iter()
+        .map(|user_id| tournament_invites::ActiveModel {
+            tournament_id: Set(tournament_id.to_owned()),
+            user_id: Set(user_id),
+            invited_at: Set(invited_at.fixed_offset()),
+            ..Default::default()
+        });
+
+    tournament_invites::Entity::insert_many(records)
+        .on_conflict(
+            OnConflict::columns([
+                tournament_invites::Column::TournamentId,
+                tournament_invites::Column::UserId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .do_nothing()
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn revoke_invite(db: &DbConn, tournament_id: &str, user_id: &str) -> Result<bool, DbErr> {
+    let result = tournament_invites::Entity::delete_many()
+        .filter(tournament_invites::Column::TournamentId.eq(tournament_id))
+        .filter(tournament_invites::Column::UserId.eq(user_id))
+        .exec(db)
+        .await?;
+
+    Ok(result.rows_affected > 0)
+}
+
+/// Replaces the tournament's invite code, invalidating the previously shared one.
+pub async fn rotate_invite_code(db: &DbConn, tournament_id: &str) -> Result<String, DbErr> {
+    let invite_code = generate_invite_code();
+
+    tournaments::ActiveModel {
+        id: Unchanged(tournament_id.to_owned()),
+        invite_code: Set(Some(invite_code.clone())),
+        ..Default::default()
+    }
+    .update(db)
+    .await?;
+
+    Ok(invite_code)
+}
+
+/// Puts the user on the invite list of the tournament the code belongs to.
+pub async fn redeem_invite_code(
+    db: &DbConn,
+    invite_code: &str,
+    user_id: &str,
+    invited_at: DateTime<Utc>,
+) -> Result<Option<tournaments::Model>, DbErr> {
+    let Some(tournament) = tournaments::Entity::find()
+        .filter(tournaments::Column::InviteCode.eq(invite_code))
+        .one(db)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    invite_users(db, &tournament.id, vec![user_id.to_owned()], invited_at).await?;
+
+    Ok(Some(tournament))
+}
+
+/// Whether the user may join the tournament. Open tournaments admit everyone;
+/// invitational ones only their creator and invited users. Unknown tournaments
+/// are not rejected here, the handshake reports those on its own.
+pub async fn can_join_tournament(
+    db: &DbConn,
+    tournament_id: &str,
+    user_id: Option<&str>,
+) -> Result<bool, DbErr> {
+    let Some(tournament) = tournaments::Entity::find_by_id(tournament_id)
+        .one(db)
+        .await?
+    else {
+        return Ok(true);
+    };
+
+    if tournament.privacy == TournamentPrivacy::Open {
+        return Ok(true);
+    }
+
+    let Some(user_id) = user_id else {
+        return Ok(false);
+    };
+
+    if tournament.created_by == user_id {
+        return Ok(true);
+    }
+
+    let invites = tournament_invites::Entity::find()
+        .filter(tournament_invites::Column::TournamentId.eq(tournament_id))
+        .filter(tournament_invites::Column::UserId.eq(user_id))
+        .count(db)
+        .await?;
+
+    Ok(invites > 0)
+}
+
+/// Filter for tournament listings: invitational tournaments are only visible to
+/// their creator and invited users.
+pub fn visible_to(user_id: Option<&str>) -> Condition {
+    let condition =
+        Condition::any().add(tournaments::Column::Privacy.ne(TournamentPrivacy::Invitational));
+
+    let Some(user_id) = user_id else {
+        return condition;
+    };
+
+    condition
+        .add(tournaments::Column::CreatedBy.eq(user_id))
+        .add(
+            tournaments::Column::Id.in_subquery(
+                Query::select()
+                    .column(tournament_invites::Column::TournamentId)
+                    .from(tournament_invites::Entity)
+                    .and_where(tournament_invites::Column::UserId.eq(user_id))
+                    .to_owned(),
+            ),
+        )
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use models::domains::{tournament_results, tournament_teams};
+use models::schemas::achievement::AchievementSchema;
+use models::schemas::tournament::{StandingSchema, TeamStandingSchema};
+use models::schemas::typing::TypingSessionSchema;
+use models::schemas::user::TournamentRoomMember;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbConn, DbErr, EntityTrait, QueryFilter, QueryOrder, Set,
+    TransactionTrait,
+};
+
+use crate::core::{KeyStats, ReplayLog, session_duration_ms, session_outcome};
+
+use super::achievements::unlock_achievements;
+use super::key_stats::record_key_stats;
+use super::race_replays::record_race_replays;
+use super::ratings::record_ratings;
+use super::typing_history::record_tournament_results;
+
+pub struct RecordedResults {
+    pub history_entries: usize,
+    /// Achievements unlocked by this race, keyed by member id.
+    pub unlocked: Vec<(String, Vec<AchievementSchema>)>,
+}
+
+/// Persists the final standings, team standings, keystroke replays, per-key error tallies,
+/// rating updates and every member's typing history in one transaction, then evaluates
+/// achievements against the updated history.
+///
+/// `standings` and `teams` must already be ordered from first to last place.
+pub async fn record_final_results(
+    db: &DbConn,
+    tournament_id: &str,
+    standings: &[TypingSessionSchema],
+    teams: &[TeamStandingSchema],
+    replays: Vec<(TournamentRoomMember, ReplayLog)>,
+    key_stats: Vec<(String, KeyStats)>,
+    tournament_ended_at: DateTime<Utc>,
+) -> Result<RecordedResults, DbErr> {
+    let aliases = anonymous_aliases(standings);
+    let txn = db.begin().await?;
+
+    record_tournament_standings(&txn, tournament_id, standings, &aliases).await?;
+    record_team_standings(&txn, tournament_id, teams).await?;
+    record_race_replays(&txn, tournament_id, replays, &aliases).await?;
+    record_key_stats(&txn, key_stats).await?;
+    record_ratings(&txn, tournament_id, standings, tournament_ended_at).await?;
+    let history_entries =
+        record_tournament_results(&txn, tournament_id, standings, tournament_ended_at).await?;
+
+    let mut unlocked = Vec::new();
+    for member in standings.iter().map(|session| &session.member) {
+        let Some(user_id) = &member.user_id else {
+            continue;
+        };
+
+        let achievements =
+            unlock_achievements(&txn, user_id, tournament_id, tournament_ended_at).await?;
+        if !achievements.is_empty() {
+            unlocked.push((member.id.clone(), achievements));
+        }
+    }
+
+    txn.commit().await?;
+
+    Ok(RecordedResults {
+        history_entries,
+        unlocked,
+    })
+}
+
+/// Members who raced anonymously under their account are stored under an id made up
+/// for this tournament, so public results and replays can't be traced back to their
+/// account.
+fn anonymous_aliases(standings: &[TypingSessionSchema]) -> HashMap<String, String> {
+    standings
+        .iter()
+        .map(|session| &session.member)
+        .filter(|member| member.user_id.is_some() && member.user.is_none())
+        .map(|member| {
+            let id_len = super::USER_ID_LENGTH;
+            (
+                member.id.clone(),
+                nanoid::nanoid!(id_len, &super::ID_ALPHABET),
+            )
+        })
+        .collect()
+}
+
+/// The id a member is listed under in the stored results and replays.
+pub(super) fn public_member_id(
+    member: &TournamentRoomMember,
+    aliases: &HashMap<String, String>,
+) -> String {
+    aliases.get(&member.id).unwrap_or(&member.id).clone()
+}
+
+/// `aliases` maps the ids of anonymous members to the ids stored instead.
+pub async fn record_tournament_standings<C: ConnectionTrait>(
+    db: &C,
+    tournament_id: &str,
+    standings: &[TypingSessionSchema],
+    aliases: &HashMap<String, String>,
+) -> Result<(), DbErr> {
+    if standings.is_empty() {
+        return Ok(());
+    }
+
+    let records =
+        standings
+            .iter()
+            .enumerate()
+            .map(|(index, session)| tournament_results::ActiveModel {
+                tournament_id: Set(tournament_id.to_owned()),
+                member_id: Set(public_member_id(&session.member, aliases)),
+                user_id: Set(session.member.user_id.clone()),
+                username: Set(session.member.user.as_ref().map(|u| u.username.clone())),
+                rank: Set(index as i32 + 1),
+                speed: Set(session.current_speed.round() as i32),
+                accuracy: Set(session.current_accuracy.round() as i32),
+                progress: Set(session.correct_position as i32),
+                started_at: Set(session.started_at.map(|v| v.fixed_offset())),
+                ended_at: Set(session.ended_at.map(|v| v.fixed_offset())),
+                duration_ms: Set(session_duration_ms(session)),
+                outcome: Set(session_outcome(session)),
+                team: Set(session.member.team.clone()),
+                ..Default::default()
+            });
+
+    tournament_results::Entity::insert_many(records)
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn record_team_standings<C: ConnectionTrait>(
+    db: &C,
+    tournament_id: &str,
+    teams: &[TeamStandingSchema],
+) -> Result<(), DbErr> {
+    if teams.is_empty() {
+        return Ok(());
+    }
+
+    let records = teams.iter().map(|team| tournament_teams::ActiveModel {
+        tournament_id: Set(tournament_id.to_owned()),
+        name: Set(team.name.clone()),
+        rank: Set(team.rank),
+        score: Set(team.score),
+        member_count: Set(team.member_count),
+        ..Default::default()
+    });
+
+    tournament_teams::Entity::insert_many(records)
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_team_standings(
+    db: &DbConn,
+    tournament_id: &str,
+) -> Result<Vec<TeamStandingSchema>, DbErr> {
+    tournament_teams::Entity::find()
+        .filter(tournament_teams::Column::TournamentId.eq(tournament_id))
+        .order_by_asc(tournament_teams::Column::Rank)
+        .all(db)
+        .await
+        .map(|teams| teams.into_iter().map(TeamStandingSchema::from).collect())
+}
+
+pub async fn get_tournament_standings(
+    db: &DbConn,
+    tournament_id: &str,
+) -> Result<Vec<StandingSchema>, DbErr> {
+    tournament_results::Entity::find()
+        .filter(tournament_results::Column::TournamentId.eq(tournament_id))
+        .order_by_asc(tournament_results::Column::Rank)
+        .all(db)
+        .await
+        .map(|results| results.into_iter().map(StandingSchema::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use models::schemas::user::TournamentRoomUserProfile;
+
+    use super::*;
+
+    fn session(id: &str, user_id: Option<&str>, anonymous: bool) -> TypingSessionSchema {
+        let member = TournamentRoomMember {
+            id: id.into(),
+            user: (!anonymous).then(|| TournamentRoomUserProfile {
+                username: id.into(),
+            }),
+            participant: true,
+            rating: None,
+            user_id: user_id.map(Into::into),
+            team: None,
+        };
+        TypingSessionSchema::new(member, "t".into())
+    }
+
+    #[test]
+    fn anonymous_members_are_stored_under_an_alias() {
+        let standings = [
+            session("named", Some("named"), false),
+            session("hidden", Some("hidden"), true),
+            session("guest", None, true),
+        ];
+        let aliases = anonymous_aliases(&standings);
+
+        assert_eq!(aliases.len(), 1);
+        assert_eq!(public_member_id(&standings[0].member, &aliases), "named");
+        assert_ne!(public_member_id(&standings[1].member, &aliases), "hidden");
+        assert_eq!(public_member_id(&standings[2].member, &aliases), "guest");
+    }
+}
@@ -0,0 +1,92 @@
+use models::domains::tournament_texts;
+use models::schemas::tournament::{RoundTextSchema, TournamentSchema};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbConn, DbErr, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+
+use super::text::{PassageRevision, race_seed, regenerate_text};
+
+/// One stretch of the text of a round: the whole text, or the stretch of a
+/// time-attack text starting `offset` characters in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextStretch {
+    pub round: i32,
+    pub offset: usize,
+    pub passage: Option<PassageRevision>,
+}
+
+/// Stores the passage revision each stretch of a tournament's texts was drawn
+/// from, which is all that the seed doesn't already tell.
+pub async fn record_tournament_texts<C: ConnectionTrait>(
+    db: &C,
+    tournament_id: &str,
+    stretches: Vec<TextStretch>,
+) -> Result<(), DbErr> {
+    if stretches.is_empty() {
+        return Ok(());
+    }
+
+    let records = stretches
+        .into_iter()
+        .map(|stretch| tournament_texts::ActiveModel {
+            tournament_id: Set(tournament_id.to_owned()),
+            round: Set(stretch.round),
+            text_offset: Set(stretch.offset as i32),
+            passage_id: Set(stretch.passage.map(|passage| passage.id)),
+            passage_revision: Set(stretch.passage.map(|passage| passage.revision)),
+            ..Default::default()
+        });
+
+    tournament_texts::Entity::insert_many(records)
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+/// Regenerates the text raced in each round, in round order. A round's text is
+/// `None` once a passage it was drawn from has been edited. Tournaments created
+/// before seeds were stored have nothing to regenerate.
+pub async fn regenerate_tournament_texts(
+    db: &DbConn,
+    tournament: &TournamentSchema,
+) -> Result<Vec<RoundTextSchema>, DbErr> {
+    let Some(text_seed) = tournament.text_seed else {
+        return Ok(Vec::new());
+    };
+    let options = tournament.text_options.unwrap_or_default();
+
+    let stretches = tournament_texts::Entity::find()
+        .filter(tournament_texts::Column::TournamentId.eq(&tournament.id))
+        .order_by_asc(tournament_texts::Column::Round)
+        .order_by_asc(tournament_texts::Column::TextOffset)
+        .all(db)
+        .await?;
+
+    let mut texts: Vec<RoundTextSchema> = Vec::new();
+    for stretch in stretches {
+        let passage = stretch
+            .passage_id
+            .zip(stretch.passage_revision)
+            .map(|(id, revision)| PassageRevision { id, revision });
+        let seed = race_seed(text_seed, stretch.round, stretch.text_offset as usize);
+        let text = regenerate_text(db, options, seed, passage).await?;
+
+        match texts.last_mut() {
+            // Time-attack stretches are joined on with a space.
+            Some(last) if last.round == stretch.round => {
+                last.text = last
+                    .text
+                    .take()
+                    .zip(text)
+                    .map(|(start, rest)| format!("{start} {rest}"));
+            }
+            _ => texts.push(RoundTextSchema {
+                round: stretch.round,
+                text,
+            }),
+        }
+    }
+
+    Ok(texts)
+}
@@ -9,13 +9,17 @@ use models::schemas::typing::TextOptions;
 use models::schemas::user::{TournamentRoomMember, UserSchema};
 use sea_orm::ActiveValue::Unchanged;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DbConn, DbErr, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, DbConn, DbErr, EntityTrait, JoinType, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set,
 };
 
 use models::domains::*;
-use models::params::tournament::{CreateTournamentParams, UpdateTournamentParams};
+use models::params::tournament::{
+    CreateTournamentParams, DEFAULT_ELIMINATIONS_PER_ROUND, DEFAULT_INACTIVITY_TIMEOUT_SECS,
+    DEFAULT_JOIN_CUTOFF_SECS, DEFAULT_RACE_DURATION_SECS, DEFAULT_ROUNDS, UpdateTournamentParams,
+};
 
+use crate::persistence::tournament_invites::{generate_invite_code, visible_to};
 use crate::state::AppState;
 
 pub async fn parse_tournament(
@@ -43,12 +47,14 @@ pub async fn parse_tournament(
     let mut started_at = tournament.started_at.map(|v| v.to_utc());
     let mut participant_count = 0;
     let mut participating = false;
+    let mut waitlist_position = None;
     let mut ended_at = tournament.ended_at.map(|v| v.to_utc());
 
     if let Some(live_data) = live_data {
         started_at = live_data.started_at;
         participant_count = live_data.participant_count;
         participating = live_data.participating;
+        waitlist_position = live_data.waitlist_position;
         ended_at = live_data.ended_at;
     }
 
@@ -61,9 +67,19 @@ pub async fn parse_tournament(
         ended_at,
         participating,
         participant_count,
+        waitlist_position,
         scheduled_for: tournament.scheduled_for.to_utc(),
         privacy: tournament.privacy,
         text_options: tournament.text_options.map(TextOptions::from_value),
+        race_duration_secs: tournament.race_duration_secs,
+        join_cutoff_secs: tournament.join_cutoff_secs,
+        inactivity_timeout_secs: tournament.inactivity_timeout_secs,
+        max_participants: tournament.max_participants,
+        team_scoring: tournament.team_scoring,
+        rounds: tournament.rounds,
+        eliminations_per_round: tournament.eliminations_per_round,
+        series_id: tournament.series_id,
+        mode: tournament.mode,
     })
 }
 
@@ -72,6 +88,39 @@ pub async fn create_tournament(
     params: CreateTournamentParams,
     user: &UserSchema,
 ) -> Result<tournaments::Model, DbErr> {
+    // Only the creator of an ended tournament may race its texts again, so a
+    // seed can't be borrowed to practise the texts of a tournament to come.
+    let rematched = match &params.rematch_of {
+        Some(id) => Some(
+            tournaments::Entity::find_by_id(id)
+                .filter(tournaments::Column::CreatedBy.eq(&user.id))
+                .filter(tournaments::Column::EndedAt.is_not_null())
+                .filter(tournaments::Column::TextSeed.is_not_null())
+                .one(db)
+                .await?
+                .ok_or_else(|| DbErr::RecordNotFound("Tournament to rematch not found".into()))?,
+        ),
+        None => None,
+    };
+
+    let mut tournament = new_tournament(params, user.id.clone());
+    // The texts follow from the seed, the text options, the mode and the rounds,
+    // so a rematch takes all of them over to race the same texts.
+    if let Some(rematched) = rematched {
+        tournament.text_seed = Set(rematched.text_seed);
+        tournament.text_options = Set(rematched.text_options);
+        tournament.language = Set(rematched.language);
+        tournament.mode = Set(rematched.mode);
+        tournament.rounds = Set(rematched.rounds);
+    }
+    tournament.insert(db).await
+}
+
+/// Builds a fresh tournament row, filling in defaults for unset settings.
+pub(crate) fn new_tournament(
+    params: CreateTournamentParams,
+    created_by: String,
+) -> tournaments::ActiveModel {
     let id_len = super::TOURNAMENT_ID_LENGTH;
     let id = nanoid::nanoid!(id_len, &super::ID_ALPHABET);
 
@@ -84,13 +133,28 @@ pub async fn create_tournament(
         scheduled_for: Set(params
             .scheduled_for
             .max(scheduled_for_earliest.fixed_offset())),
-        created_by: Set(user.id.clone()),
-        privacy: Set(TournamentPrivacy::Open),
+        created_by: Set(created_by),
+        privacy: Set(params.privacy.unwrap_or(TournamentPrivacy::Open)),
+        language: Set(params.text_options.unwrap_or_default().language),
         text_options: Set(params.text_options.map(TextOptions::to_value)),
+        invite_code: Set(Some(generate_invite_code())),
+        race_duration_secs: Set(params
+            .race_duration_secs
+            .unwrap_or(DEFAULT_RACE_DURATION_SECS)),
+        join_cutoff_secs: Set(params.join_cutoff_secs.unwrap_or(DEFAULT_JOIN_CUTOFF_SECS)),
+        inactivity_timeout_secs: Set(params
+            .inactivity_timeout_secs
+            .unwrap_or(DEFAULT_INACTIVITY_TIMEOUT_SECS)),
+        max_participants: Set(params.max_participants),
+        team_scoring: Set(params.team_scoring),
+        rounds: Set(params.rounds.unwrap_or(DEFAULT_ROUNDS)),
+        eliminations_per_round: Set(params
+            .eliminations_per_round
+            .unwrap_or(DEFAULT_ELIMINATIONS_PER_ROUND)),
+        mode: Set(params.mode.unwrap_or_default()),
+        text_seed: Set(Some(rand::random())),
         ..Default::default()
     }
-    .insert(db)
-    .await
 }
 
 pub async fn update_tournament(
@@ -116,7 +180,44 @@ pub async fn update_tournament(
     }
 
     if let Some(scheduled_for) = params.scheduled_for {
-        tournament.scheduled_for = Set(scheduled_for);
+        let scheduled_for_earliest = Utc::now() + Duration::from_secs(30);
+        tournament.scheduled_for = Set(scheduled_for.max(scheduled_for_earliest.fixed_offset()));
+    }
+
+    if let Some(privacy) = params.privacy {
+        tournament.privacy = Set(privacy);
+    }
+
+    if let Some(race_duration_secs) = params.race_duration_secs {
+        tournament.race_duration_secs = Set(race_duration_secs);
+    }
+
+    if let Some(join_cutoff_secs) = params.join_cutoff_secs {
+        tournament.join_cutoff_secs = Set(join_cutoff_secs);
+    }
+
+    if let Some(inactivity_timeout_secs) = params.inactivity_timeout_secs {
+        tournament.inactivity_timeout_secs = Set(inactivity_timeout_secs);
+    }
+
+    if let Some(max_participants) = params.max_participants {
+        tournament.max_participants = Set(max_participants);
+    }
+
+    if let Some(team_scoring) = params.team_scoring {
+        tournament.team_scoring = Set(team_scoring);
+    }
+
+    if let Some(rounds) = params.rounds {
+        tournament.rounds = Set(rounds);
+    }
+
+    if let Some(eliminations_per_round) = params.eliminations_per_round {
+        tournament.eliminations_per_round = Set(eliminations_per_round);
+    }
+
+    if let Some(mode) = params.mode {
+        tournament.mode = Set(mode);
     }
 
     if let Some(ended_at) = params.ended_at {
@@ -124,12 +225,18 @@ pub async fn update_tournament(
     }
 
     if let Some(text_options) = params.text_options {
+        tournament.language = Set(text_options.unwrap_or_default().language);
         tournament.text_options = Set(text_options.map(TextOptions::to_value));
     }
 
     tournament.update(&state.conn).await
 }
 
+pub async fn delete_tournament(db: &DbConn, id: &str) -> Result<(), DbErr> {
+    tournaments::Entity::delete_by_id(id).exec(db).await?;
+    Ok(())
+}
+
 pub async fn search_tournaments(
     app_state: &AppState,
     query: TournamentPaginationQuery,
@@ -140,44 +247,59 @@ pub async fn search_tournaments(
     let page = query.page.unwrap_or(1);
     let offset = (page - 1) * limit;
 
-    let total = tournaments::Entity::find().count(&app_state.conn).await?;
-    let data = {
-        let mut res = Vec::new();
-
-        let mut sql_query = tournaments::Entity::find();
+    let mut sql_query = tournaments::Entity::find()
+        .filter(visible_to(user_id))
+        .filter(tournaments::Column::Practice.eq(false));
 
-        if let Some(privacy) = query.privacy {
-            sql_query = sql_query.filter(tournaments::Column::Privacy.eq(privacy))
-        }
+    if let Some(privacy) = query.privacy {
+        sql_query = sql_query.filter(tournaments::Column::Privacy.eq(privacy))
+    }
 
-        if let Some(status) = query.status {
-            match status {
-                models::schemas::typing::TournamentStatus::Upcoming => {
-                    sql_query = sql_query.filter(tournaments::Column::ScheduledFor.gt(Utc::now()))
-                }
-                models::schemas::typing::TournamentStatus::Started => {
-                    sql_query = sql_query.filter(
-                        tournaments::Column::StartedAt
-                            .is_not_null()
-                            .and(tournaments::Column::EndedAt.is_null()),
-                    )
-                }
-                models::schemas::typing::TournamentStatus::Ended => {
-                    sql_query = sql_query.filter(tournaments::Column::EndedAt.is_not_null())
-                }
+    if let Some(status) = query.status {
+        match status {
+            models::schemas::typing::TournamentStatus::Upcoming => {
+                sql_query = sql_query.filter(tournaments::Column::ScheduledFor.gt(Utc::now()))
+            }
+            models::schemas::typing::TournamentStatus::Started => {
+                sql_query = sql_query.filter(
+                    tournaments::Column::StartedAt
+                        .is_not_null()
+                        .and(tournaments::Column::EndedAt.is_null()),
+                )
+            }
+            models::schemas::typing::TournamentStatus::Ended => {
+                sql_query = sql_query.filter(tournaments::Column::EndedAt.is_not_null())
             }
         }
+    }
 
-        if let Some(search) = query.search {
-            if !search.is_empty() {
-                sql_query = sql_query.filter(
+    if let Some(language) = query.language {
+        sql_query = sql_query.filter(tournaments::Column::Language.eq(language))
+    }
+
+    if let Some(search) = query.search {
+        if !search.is_empty() {
+            sql_query = sql_query
+                .join(
+                    JoinType::InnerJoin,
+                    tournaments::Entity::belongs_to(users::Entity)
+                        .from(tournaments::Column::CreatedBy)
+                        .to(users::Column::Id)
+                        .into(),
+                )
+                .filter(
                     tournaments::Column::Title
                         .like(&search)
                         .or(tournaments::Column::Description.like(&search))
                         .or(users::Column::Username.like(&search)),
                 );
-            }
         }
+    }
+
+    // Counted with the same filters, so the total matches the pages.
+    let total = sql_query.clone().count(&app_state.conn).await?;
+    let data = {
+        let mut res = Vec::new();
 
         let sql_query = sql_query
             .order_by_asc(tournaments::Column::ScheduledFor)
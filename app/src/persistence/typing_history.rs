@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use models::domains::typing_history;
+use models::queries::typing_history::{SortOrder, TypingHistoryQuery, TypingHistorySortBy};
+use models::schemas::pagination::PaginatedData;
+use models::schemas::typing::TypingSessionSchema;
+use models::schemas::typing_history::TypingHistorySchema;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbConn, DbErr, EntityTrait, Order, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set,
+};
+use tracing::info;
+
+use crate::core::{session_duration_ms, session_outcome};
+
+/// Writes the final result of every account-backed session of a tournament.
+///
+/// `standings` must already be ordered from first to last place; the position in the
+/// slice becomes the stored placement. Members without an account have nothing to attach
+/// a record to and are skipped. Members who raced anonymously are stored with the
+/// `anonymous` flag so public views can keep hiding them.
+pub async fn record_tournament_results<C: ConnectionTrait>(
+    db: &C,
+    tournament_id: &str,
+    standings: &[TypingSessionSchema],
+    tournament_ended_at: DateTime<Utc>,
+) -> Result<usize, DbErr> {
+    let records = standings
+        .iter()
+        .enumerate()
+        .filter_map(|(index, session)| {
+            let Some(user_id) = session.member.user_id.clone() else {
+                info!(
+                    member_id = %session.member.id,
+                    tournament_id,
+                    "Skipping history for unauthenticated member"
+                );
+                return None;
+            };
+
+            Some(typing_history::ActiveModel {
+                user_id: Set(user_id),
+                tournament_id: Set(tournament_id.to_owned()),
+                accuracy: Set(session.current_accuracy.round() as i32),
+                speed: Set(session.current_speed.round() as i32),
+                completed_at: Set(session
+                    .ended_at
+                    .unwrap_or(tournament_ended_at)
+                    .fixed_offset()),
+                placement: Set(index as i32 + 1),
+                duration_ms: Set(session_duration_ms(session)),
+                outcome: Set(session_outcome(session)),
+                anonymous: Set(session.member.user.is_none()),
+                ..Default::default()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let count = records.len();
+
+    if count > 0 {
+        typing_history::Entity::insert_many(records)
+            .exec(db)
+            .await?;
+    }
+
+    Ok(count)
+}
+
+const MAX_HISTORY_PAGE_SIZE: u64 = 100;
+
+/// Lists a user's typing history, newest first unless the query says otherwise.
+///
+/// Entries recorded while the user raced anonymously are only included when
+/// `include_anonymous` is set, i.e. when the user is looking at their own history.
+pub async fn get_user_history(
+    db: &DbConn,
+    user_id: &str,
+    query: TypingHistoryQuery,
+    include_anonymous: bool,
+) -> Result<PaginatedData<TypingHistorySchema>, DbErr> {
+    let limit = query.limit.unwrap_or(20).clamp(1, MAX_HISTORY_PAGE_SIZE);
+    let page = query.page.unwrap_or(1).max(1);
+    let offset = (page - 1) * limit;
+
+    let mut sql_query =
+        typing_history::Entity::find().filter(typing_history::Column::UserId.eq(user_id));
+
+    if !include_anonymous {
+        sql_query = sql_query.filter(typing_history::Column::Anonymous.eq(false));
+    }
+
+    if let Some(from) = query.from {
+        sql_query = sql_query.filter(typing_history::Column::CompletedAt.gte(from));
+    }
+
+    if let Some(to) = query.to {
+        sql_query = sql_query.filter(typing_history::Column::CompletedAt.lte(to));
+    }
+
+    if let Some(min_speed) = query.min_speed {
+        sql_query = sql_query.filter(typing_history::Column::Speed.gte(min_speed));
+    }
+
+    if let Some(max_speed) = query.max_speed {
+        sql_query = sql_query.filter(typing_history::Column::Speed.lte(max_speed));
+    }
+
+    let total = sql_query.clone().count(db).await?;
+
+    let order = match query.order.unwrap_or_default() {
+        SortOrder::Asc => Order::Asc,
+        SortOrder::Desc => Order::Desc,
+    };
+
+    let sort_column = match query.sort_by.unwrap_or_default() {
+        TypingHistorySortBy::Date => typing_history::Column::CompletedAt,
+        TypingHistorySortBy::Speed => typing_history::Column::Speed,
+        TypingHistorySortBy::Accuracy => typing_history::Column::Accuracy,
+    };
+
+    let data = sql_query
+        .order_by(sort_column, order)
+        .order_by_desc(typing_history::Column::Id)
+        .offset(offset)
+        .limit(limit)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(TypingHistorySchema::from)
+        .collect();
+
+    Ok(PaginatedData::new(data, page, limit, total))
+}
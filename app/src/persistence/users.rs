@@ -1,6 +1,7 @@
 use fake::{Fake, faker};
 use rand::Rng;
 use rand::{SeedableRng, rngs::StdRng};
+use sea_orm::sea_query::{Expr, Func, SimpleExpr};
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, DbErr, EntityTrait, IntoActiveModel, QueryFilter, Set,
 };
@@ -20,6 +21,16 @@ const OTP_DURATION: TimeDelta = TimeDelta::minutes(10);
 
 const USERNAME_SUFFIX_LENGTH: usize = 6;
 
+/// Emails are stored trimmed and lowercased so one inbox can't back two accounts.
+fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// Accounts stored before emails were normalized may still have uppercase letters.
+fn email_is(email: &str) -> SimpleExpr {
+    Expr::expr(Func::lower(Expr::col(users::Column::Email))).eq(normalize_email(email))
+}
+
 pub async fn create_user(
     state: &AppState,
     params: CreateUserParams,
@@ -27,8 +38,10 @@ pub async fn create_user(
     let pass_hash = bcrypt::hash(params.password, 12)
         .map_err(|_| DbErr::Custom("Authentication setup failed".to_string()))?;
 
+    let email = normalize_email(&params.email);
+
     let existing_user = users::Entity::find()
-        .filter(users::Column::Email.eq(&params.email))
+        .filter(email_is(&email))
         .one(&state.conn)
         .await?;
 
@@ -47,7 +60,7 @@ pub async fn create_user(
 
     users::ActiveModel {
         id: Set(id),
-        email: Set(params.email),
+        email: Set(email),
         passhash: Set(Some(pass_hash)),
         username: Set(username),
         ..Default::default()
@@ -96,7 +109,7 @@ pub async fn login_user(
     LoginUserParams { email, password }: LoginUserParams,
 ) -> Result<users::Model, DbErr> {
     let user = match users::Entity::find()
-        .filter(users::Column::Email.eq(&email))
+        .filter(email_is(&email))
         .one(&state.conn)
         .await?
     {
@@ -122,10 +135,10 @@ pub async fn forgot_password(
 ) -> Result<models::domains::otp::Model, anyhow::Error> {
     let now = Utc::now();
     let db = &state.conn;
-    let email = body.email.trim().to_lowercase();
+    let email = normalize_email(&body.email);
 
     let user = users::Entity::find()
-        .filter(users::Column::Email.eq(email.clone()))
+        .filter(email_is(&email))
         .one(db)
         .await?;
 
@@ -177,12 +190,11 @@ pub async fn reset_password(state: &AppState, params: ResetPasswordBody) -> Resu
     use models::domains::otp;
     use sea_orm::TransactionTrait;
 
+    let email = normalize_email(&params.email);
     let txn = state.conn.begin().await?;
 
     // 1. Find OTP record for the email
-    let otp_record = otp::Entity::find_by_id(params.email.clone())
-        .one(&txn)
-        .await?;
+    let otp_record = otp::Entity::find_by_id(email.clone()).one(&txn).await?;
     let otp_record = match otp_record {
         Some(r) => r,
         None => {
@@ -207,7 +219,7 @@ pub async fn reset_password(state: &AppState, params: ResetPasswordBody) -> Resu
 
     // 4. Update user's password
     let user = users::Entity::find()
-        .filter(users::Column::Email.eq(params.email.clone()))
+        .filter(email_is(&email))
         .one(&txn)
         .await?;
     let mut user = match user {
@@ -221,9 +233,7 @@ pub async fn reset_password(state: &AppState, params: ResetPasswordBody) -> Resu
     user.update(&txn).await?;
 
     // 5. Delete OTP
-    otp::Entity::delete_by_id(params.email.clone())
-        .exec(&txn)
-        .await?;
+    otp::Entity::delete_by_id(email).exec(&txn).await?;
 
     // 6. Commit transaction
     txn.commit().await?;
@@ -234,13 +244,14 @@ pub async fn email_auth(state: &AppState, params: EmailAuthParams) -> Result<use
     use sea_orm::TransactionTrait;
     // Start a transaction with the highest isolation level to prevent race conditions.
     // Serializable makes the transaction behave as if it's the only one running.
+    let email = normalize_email(&params.email);
     let txn = state.conn.begin().await?;
 
     // --- The entire operation now happens within the transaction ---
 
     // 1. Check for the user *within* the transaction.
     let existing_user = users::Entity::find()
-        .filter(users::Column::Email.eq(&params.email))
+        .filter(email_is(&email))
         .one(&txn) // Use the transaction connection `&txn`
         .await?;
 
@@ -261,7 +272,7 @@ pub async fn email_auth(state: &AppState, params: EmailAuthParams) -> Result<use
 
         users::ActiveModel {
             id: Set(id),
-            email: Set(params.email),
+            email: Set(email),
             username: Set(username),
             passhash: Set(None),
             ..Default::default()
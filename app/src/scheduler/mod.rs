@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use chrono::{DateTime, Utc};
 use tokio::time::Instant;
 
@@ -17,3 +19,18 @@ where
         Err("Scheduled time is in the past".into())
     }
 }
+
+/// Runs `task` every `period`, starting one period from now.
+pub fn schedule_repeating_task<F, Fut>(period: Duration, task: F)
+where
+    F: Fn() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval_at(Instant::now() + period, period);
+        loop {
+            interval.tick().await;
+            task().await;
+        }
+    });
+}
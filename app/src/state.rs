@@ -2,7 +2,7 @@ use sea_orm::DatabaseConnection;
 use socketioxide::SocketIo;
 
 use crate::{
-    cache::{TournamentRegistry, TypingSessionRegistry},
+    cache::{LeaderboardCache, TournamentRegistry, TypingSessionRegistry},
     config::Config,
 };
 
@@ -12,5 +12,6 @@ pub struct AppState {
     pub config: Config,
     pub tournament_registry: TournamentRegistry,
     pub typing_session_registry: TypingSessionRegistry,
+    pub leaderboard_cache: LeaderboardCache,
     pub socket_io: SocketIo,
 }
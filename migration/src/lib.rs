@@ -1,12 +1,56 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20220101_000001_create_table;
+mod m20250901_000001_typing_history_results;
+mod m20250915_000001_create_tournament_results;
+mod m20250922_000001_create_race_replays;
+mod m20250929_000001_create_key_stats;
+mod m20251006_000001_create_ratings;
+mod m20251013_000001_create_user_achievements;
+mod m20251020_000001_create_tournament_invites;
+mod m20251027_000001_add_tournament_timing;
+mod m20251103_000001_create_tournament_series;
+mod m20251110_000001_add_tournament_capacity;
+mod m20251117_000001_create_tournament_teams;
+mod m20251124_000001_add_tournament_rounds;
+mod m20251201_000001_add_tournament_practice;
+mod m20251208_000001_create_tournament_bans;
+mod m20251215_000001_add_tournament_mode;
+mod m20251222_000001_create_passages;
+mod m20251229_000001_add_text_language;
+mod m20260105_000001_add_tournament_text_seed;
+mod m20260112_000001_add_user_role;
+mod m20260119_000001_add_passage_revision;
+mod m20260119_000002_create_tournament_texts;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_table::Migration)]
+        vec![
+            Box::new(m20220101_000001_create_table::Migration),
+            Box::new(m20250901_000001_typing_history_results::Migration),
+            Box::new(m20250915_000001_create_tournament_results::Migration),
+            Box::new(m20250922_000001_create_race_replays::Migration),
+            Box::new(m20250929_000001_create_key_stats::Migration),
+            Box::new(m20251006_000001_create_ratings::Migration),
+            Box::new(m20251013_000001_create_user_achievements::Migration),
+            Box::new(m20251020_000001_create_tournament_invites::Migration),
+            Box::new(m20251027_000001_add_tournament_timing::Migration),
+            Box::new(m20251103_000001_create_tournament_series::Migration),
+            Box::new(m20251110_000001_add_tournament_capacity::Migration),
+            Box::new(m20251117_000001_create_tournament_teams::Migration),
+            Box::new(m20251124_000001_add_tournament_rounds::Migration),
+            Box::new(m20251201_000001_add_tournament_practice::Migration),
+            Box::new(m20251208_000001_create_tournament_bans::Migration),
+            Box::new(m20251215_000001_add_tournament_mode::Migration),
+            Box::new(m20251222_000001_create_passages::Migration),
+            Box::new(m20251229_000001_add_text_language::Migration),
+            Box::new(m20260105_000001_add_tournament_text_seed::Migration),
+            Box::new(m20260112_000001_add_user_role::Migration),
+            Box::new(m20260119_000001_add_passage_revision::Migration),
+            Box::new(m20260119_000002_create_tournament_texts::Migration),
+        ]
     }
 }
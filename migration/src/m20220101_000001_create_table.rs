@@ -12,10 +12,13 @@ pub struct Migration;
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        let schema = Schema::new(DbBackend::Postgres);
-        manager
-            .create_type(schema.create_enum_from_active_enum::<TournamentPrivacy>())
-            .await?;
+        // Only Postgres has enum types; other backends store the enum as text.
+        if manager.get_database_backend() == DbBackend::Postgres {
+            let schema = Schema::new(DbBackend::Postgres);
+            manager
+                .create_type(schema.create_enum_from_active_enum::<TournamentPrivacy>())
+                .await?;
+        }
 
         manager
             .create_table(
@@ -233,14 +236,16 @@ impl MigrationTrait for Migration {
             .drop_table(Table::drop().table(users::Entity).to_owned())
             .await?;
 
-        manager
-            .drop_type(
-                Type::drop()
-                    .name(sea_orm_active_enums::TournamentPrivacyEnum)
-                    .if_exists()
-                    .to_owned(),
-            )
-            .await?;
+        if manager.get_database_backend() == DbBackend::Postgres {
+            manager
+                .drop_type(
+                    Type::drop()
+                        .name(sea_orm_active_enums::TournamentPrivacyEnum)
+                        .if_exists()
+                        .to_owned(),
+                )
+                .await?;
+        }
 
         Ok(())
     }
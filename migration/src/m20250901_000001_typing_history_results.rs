@@ -0,0 +1,108 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Sqlite only accepts a single alteration per statement, hence one call per column.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(typing_history::Entity)
+                    .add_column(
+                        ColumnDef::new(typing_history::Column::Placement)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(typing_history::Entity)
+                    .add_column(
+                        ColumnDef::new(typing_history::Column::DurationMs)
+                            .big_integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(typing_history::Entity)
+                    .add_column(
+                        ColumnDef::new(typing_history::Column::Outcome)
+                            .string_len(16)
+                            .not_null()
+                            .default("finished"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(typing_history::Entity)
+                    .add_column(
+                        ColumnDef::new(typing_history::Column::Anonymous)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-typing_history-user_id-completed_at")
+                    .table(typing_history::Entity)
+                    .col(typing_history::Column::UserId)
+                    .col(typing_history::Column::CompletedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-typing_history-user_id-completed_at")
+                    .table(typing_history::Entity)
+                    .to_owned(),
+            )
+            .await?;
+
+        for column in [
+            typing_history::Column::Anonymous,
+            typing_history::Column::Outcome,
+            typing_history::Column::DurationMs,
+            typing_history::Column::Placement,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(typing_history::Entity)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
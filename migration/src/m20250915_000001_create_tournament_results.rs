@@ -0,0 +1,125 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(tournament_results::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(tournament_results::Column::Id)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_results::Column::TournamentId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_results::Column::MemberId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_results::Column::UserId)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_results::Column::Username)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_results::Column::Rank)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_results::Column::Speed)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_results::Column::Accuracy)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_results::Column::Progress)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_results::Column::StartedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_results::Column::EndedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_results::Column::DurationMs)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_results::Column::Outcome)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-tournament_results-tournament_id")
+                            .from(
+                                tournament_results::Entity,
+                                tournament_results::Column::TournamentId,
+                            )
+                            .to(tournaments::Entity, tournaments::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-tournament_results-user_id")
+                            .from(
+                                tournament_results::Entity,
+                                tournament_results::Column::UserId,
+                            )
+                            .to(users::Entity, users::Column::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-tournament_results-tournament_id-member_id")
+                    .table(tournament_results::Entity)
+                    .col(tournament_results::Column::TournamentId)
+                    .col(tournament_results::Column::MemberId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(tournament_results::Entity).to_owned())
+            .await
+    }
+}
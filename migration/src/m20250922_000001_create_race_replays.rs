@@ -0,0 +1,108 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .add_column(ColumnDef::new(tournaments::Column::Text).text().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(race_replays::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(race_replays::Column::Id)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(race_replays::Column::TournamentId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(race_replays::Column::MemberId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(race_replays::Column::UserId).string().null())
+                    .col(
+                        ColumnDef::new(race_replays::Column::Username)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(race_replays::Column::StartedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(race_replays::Column::EventCount)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(race_replays::Column::Events)
+                            .blob()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-race_replays-tournament_id")
+                            .from(race_replays::Entity, race_replays::Column::TournamentId)
+                            .to(tournaments::Entity, tournaments::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-race_replays-user_id")
+                            .from(race_replays::Entity, race_replays::Column::UserId)
+                            .to(users::Entity, users::Column::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-race_replays-tournament_id-member_id")
+                    .table(race_replays::Entity)
+                    .col(race_replays::Column::TournamentId)
+                    .col(race_replays::Column::MemberId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(race_replays::Entity).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .drop_column(tournaments::Column::Text)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,82 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(key_stats::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(key_stats::Column::Id)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(key_stats::Column::UserId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(key_stats::Column::Sequence)
+                            .string_len(8)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(key_stats::Column::Attempts)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(key_stats::Column::Errors)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(key_stats::Column::LatencyMs)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(key_stats::Column::LatencySamples)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-key_stats-user_id")
+                            .from(key_stats::Entity, key_stats::Column::UserId)
+                            .to(users::Entity, users::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Also the conflict target of the per-race upsert.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-key_stats-user_id-sequence")
+                    .table(key_stats::Entity)
+                    .col(key_stats::Column::UserId)
+                    .col(key_stats::Column::Sequence)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(key_stats::Entity).to_owned())
+            .await
+    }
+}
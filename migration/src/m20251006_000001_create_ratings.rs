@@ -0,0 +1,127 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ratings::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ratings::Column::UserId)
+                            .string()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ratings::Column::Rating).integer().not_null())
+                    .col(ColumnDef::new(ratings::Column::Games).integer().not_null())
+                    .col(
+                        ColumnDef::new(ratings::Column::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-ratings-user_id")
+                            .from(ratings::Entity, ratings::Column::UserId)
+                            .to(users::Entity, users::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(rating_history::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(rating_history::Column::Id)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(rating_history::Column::UserId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(rating_history::Column::TournamentId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(rating_history::Column::RatingBefore)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(rating_history::Column::RatingAfter)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(rating_history::Column::Placement)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(rating_history::Column::Anonymous)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(rating_history::Column::RecordedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-rating_history-user_id")
+                            .from(rating_history::Entity, rating_history::Column::UserId)
+                            .to(users::Entity, users::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-rating_history-tournament_id")
+                            .from(rating_history::Entity, rating_history::Column::TournamentId)
+                            .to(tournaments::Entity, tournaments::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-rating_history-user_id-recorded_at")
+                    .table(rating_history::Entity)
+                    .col(rating_history::Column::UserId)
+                    .col(rating_history::Column::RecordedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(rating_history::Entity).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ratings::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,82 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(user_achievements::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(user_achievements::Column::Id)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(user_achievements::Column::UserId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(user_achievements::Column::AchievementId)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(user_achievements::Column::TournamentId)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(user_achievements::Column::UnlockedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-user_achievements-user_id")
+                            .from(user_achievements::Entity, user_achievements::Column::UserId)
+                            .to(users::Entity, users::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-user_achievements-tournament_id")
+                            .from(
+                                user_achievements::Entity,
+                                user_achievements::Column::TournamentId,
+                            )
+                            .to(tournaments::Entity, tournaments::Column::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-user_achievements-user_id-achievement_id")
+                    .table(user_achievements::Entity)
+                    .col(user_achievements::Column::UserId)
+                    .col(user_achievements::Column::AchievementId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(user_achievements::Entity).to_owned())
+            .await
+    }
+}
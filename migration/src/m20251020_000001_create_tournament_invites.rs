@@ -0,0 +1,123 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .add_column(
+                        ColumnDef::new(tournaments::Column::InviteCode)
+                            .string_len(16)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Sqlite can't add a unique column, so uniqueness comes from an index.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-tournaments-invite_code")
+                    .table(tournaments::Entity)
+                    .col(tournaments::Column::InviteCode)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(tournament_invites::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(tournament_invites::Column::Id)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_invites::Column::TournamentId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_invites::Column::UserId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_invites::Column::InvitedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-tournament_invites-tournament_id")
+                            .from(
+                                tournament_invites::Entity,
+                                tournament_invites::Column::TournamentId,
+                            )
+                            .to(tournaments::Entity, tournaments::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-tournament_invites-user_id")
+                            .from(
+                                tournament_invites::Entity,
+                                tournament_invites::Column::UserId,
+                            )
+                            .to(users::Entity, users::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-tournament_invites-tournament_id-user_id")
+                    .table(tournament_invites::Entity)
+                    .col(tournament_invites::Column::TournamentId)
+                    .col(tournament_invites::Column::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(tournament_invites::Entity).to_owned())
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-tournaments-invite_code")
+                    .table(tournaments::Entity)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .drop_column(tournaments::Column::InviteCode)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,84 @@
+use models::domains::*;
+use models::params::tournament::{
+    DEFAULT_INACTIVITY_TIMEOUT_SECS, DEFAULT_JOIN_CUTOFF_SECS, DEFAULT_RACE_DURATION_SECS,
+};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .add_column(
+                        ColumnDef::new(tournaments::Column::RaceDurationSecs)
+                            .integer()
+                            .not_null()
+                            .default(DEFAULT_RACE_DURATION_SECS),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .add_column(
+                        ColumnDef::new(tournaments::Column::JoinCutoffSecs)
+                            .integer()
+                            .not_null()
+                            .default(DEFAULT_JOIN_CUTOFF_SECS),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .add_column(
+                        ColumnDef::new(tournaments::Column::InactivityTimeoutSecs)
+                            .integer()
+                            .not_null()
+                            .default(DEFAULT_INACTIVITY_TIMEOUT_SECS),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .drop_column(tournaments::Column::InactivityTimeoutSecs)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .drop_column(tournaments::Column::JoinCutoffSecs)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .drop_column(tournaments::Column::RaceDurationSecs)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,170 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DbBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(tournament_series::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(tournament_series::Column::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_series::Column::Title)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_series::Column::Description)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_series::Column::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_series::Column::Recurrence)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_series::Column::TextOptions)
+                            .json_binary()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_series::Column::RaceDurationSecs)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_series::Column::JoinCutoffSecs)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_series::Column::InactivityTimeoutSecs)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_series::Column::Active)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_series::Column::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-tournament_series-created_by")
+                            .from(
+                                tournament_series::Entity,
+                                tournament_series::Column::CreatedBy,
+                            )
+                            .to(users::Entity, users::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .add_column(
+                        ColumnDef::new(tournaments::Column::SeriesId)
+                            .string()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Sqlite can't add a foreign key to an existing table.
+        if manager.get_database_backend() != DbBackend::Sqlite {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(tournaments::Entity)
+                        .add_foreign_key(
+                            TableForeignKey::new()
+                                .name("fk-tournaments-series_id")
+                                .from_tbl(tournaments::Entity)
+                                .from_col(tournaments::Column::SeriesId)
+                                .to_tbl(tournament_series::Entity)
+                                .to_col(tournament_series::Column::Id)
+                                .on_delete(ForeignKeyAction::SetNull)
+                                .on_update(ForeignKeyAction::NoAction),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-tournaments-series_id-scheduled_for")
+                    .table(tournaments::Entity)
+                    .col(tournaments::Column::SeriesId)
+                    .col(tournaments::Column::ScheduledFor)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Sqlite {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(tournaments::Entity)
+                        .drop_foreign_key(Alias::new("fk-tournaments-series_id"))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-tournaments-series_id-scheduled_for")
+                    .table(tournaments::Entity)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .drop_column(tournaments::Column::SeriesId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(tournament_series::Entity).to_owned())
+            .await
+    }
+}
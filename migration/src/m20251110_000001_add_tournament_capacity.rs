@@ -0,0 +1,30 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .add_column(ColumnDef::new(tournaments::Column::MaxParticipants).integer())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .drop_column(tournaments::Column::MaxParticipants)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,123 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .add_column(
+                        ColumnDef::new(tournaments::Column::TeamScoring)
+                            .string_len(16)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournament_results::Entity)
+                    .add_column(
+                        ColumnDef::new(tournament_results::Column::Team)
+                            .string_len(32)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(tournament_teams::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(tournament_teams::Column::Id)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_teams::Column::TournamentId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_teams::Column::Name)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_teams::Column::Rank)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_teams::Column::Score)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_teams::Column::MemberCount)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-tournament_teams-tournament_id")
+                            .from(
+                                tournament_teams::Entity,
+                                tournament_teams::Column::TournamentId,
+                            )
+                            .to(tournaments::Entity, tournaments::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-tournament_teams-tournament_id-name")
+                    .table(tournament_teams::Entity)
+                    .col(tournament_teams::Column::TournamentId)
+                    .col(tournament_teams::Column::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(tournament_teams::Entity).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournament_results::Entity)
+                    .drop_column(tournament_results::Column::Team)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .drop_column(tournaments::Column::TeamScoring)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,59 @@
+use models::domains::*;
+use models::params::tournament::{DEFAULT_ELIMINATIONS_PER_ROUND, DEFAULT_ROUNDS};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .add_column(
+                        ColumnDef::new(tournaments::Column::Rounds)
+                            .integer()
+                            .not_null()
+                            .default(DEFAULT_ROUNDS),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .add_column(
+                        ColumnDef::new(tournaments::Column::EliminationsPerRound)
+                            .integer()
+                            .not_null()
+                            .default(DEFAULT_ELIMINATIONS_PER_ROUND),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .drop_column(tournaments::Column::EliminationsPerRound)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(tournaments::Entity)
+                    .drop_column(tournaments::Column::Rounds)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,69 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(tournament_bans::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(tournament_bans::Column::Id)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_bans::Column::TournamentId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_bans::Column::MemberId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_bans::Column::BannedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-tournament_bans-tournament_id")
+                            .from(
+                                tournament_bans::Entity,
+                                tournament_bans::Column::TournamentId,
+                            )
+                            .to(tournaments::Entity, tournaments::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-tournament_bans-tournament_id-member_id")
+                    .table(tournament_bans::Entity)
+                    .col(tournament_bans::Column::TournamentId)
+                    .col(tournament_bans::Column::MemberId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(tournament_bans::Entity).to_owned())
+            .await
+    }
+}
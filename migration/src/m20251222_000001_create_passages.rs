@@ -0,0 +1,79 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(passages::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(passages::Column::Id)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(passages::Column::Text).text().not_null())
+                    .col(ColumnDef::new(passages::Column::Source).string().not_null())
+                    .col(ColumnDef::new(passages::Column::Author).string().null())
+                    .col(
+                        ColumnDef::new(passages::Column::Tags)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(passages::Column::Difficulty)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(passages::Column::WordCount)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(passages::Column::Status)
+                            .string_len(16)
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(
+                        ColumnDef::new(passages::Column::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(passages::Column::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-passages-status-difficulty-word_count")
+                    .table(passages::Entity)
+                    .col(passages::Column::Status)
+                    .col(passages::Column::Difficulty)
+                    .col(passages::Column::WordCount)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(passages::Entity).to_owned())
+            .await
+    }
+}
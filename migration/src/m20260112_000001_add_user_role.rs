@@ -0,0 +1,35 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(users::Entity)
+                    .add_column(
+                        ColumnDef::new(users::Column::Role)
+                            .string_len(16)
+                            .not_null()
+                            .default("user"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(users::Entity)
+                    .drop_column(users::Column::Role)
+                    .to_owned(),
+            )
+            .await
+    }
+}
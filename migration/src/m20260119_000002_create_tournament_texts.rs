@@ -0,0 +1,72 @@
+use models::domains::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(tournament_texts::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(tournament_texts::Column::Id)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_texts::Column::TournamentId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_texts::Column::Round)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(tournament_texts::Column::TextOffset)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(tournament_texts::Column::PassageId).integer())
+                    .col(ColumnDef::new(tournament_texts::Column::PassageRevision).integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-tournament_texts-tournament_id")
+                            .from(
+                                tournament_texts::Entity,
+                                tournament_texts::Column::TournamentId,
+                            )
+                            .to(tournaments::Entity, tournaments::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-tournament_texts-tournament_id-round-text_offset")
+                    .table(tournament_texts::Entity)
+                    .col(tournament_texts::Column::TournamentId)
+                    .col(tournament_texts::Column::Round)
+                    .col(tournament_texts::Column::TextOffset)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(tournament_texts::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,36 @@
+use sea_orm::entity::prelude::*;
+
+/// Lifetime error tally of one user for a single key or a bigram.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "key_stats")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub user_id: String,
+    /// The expected character, or the preceding and expected characters for a bigram.
+    pub sequence: String,
+    pub attempts: i64,
+    pub errors: i64,
+    pub latency_ms: i64,
+    pub latency_samples: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Users,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Users.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
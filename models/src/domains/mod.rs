@@ -1,5 +1,17 @@
+pub mod key_stats;
 pub mod otp;
+pub mod passages;
+pub mod race_replays;
+pub mod rating_history;
+pub mod ratings;
 pub mod sea_orm_active_enums;
+pub mod tournament_bans;
+pub mod tournament_invites;
+pub mod tournament_results;
+pub mod tournament_series;
+pub mod tournament_teams;
+pub mod tournament_texts;
 pub mod tournaments;
 pub mod typing_history;
+pub mod user_achievements;
 pub mod users;
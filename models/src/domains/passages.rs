@@ -0,0 +1,27 @@
+use super::sea_orm_active_enums::{PassageStatus, TextDifficulty, TextLanguage};
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "passages")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(column_type = "Text")]
+    pub text: String,
+    pub source: String,
+    pub author: Option<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub tags: Json,
+    pub difficulty: TextDifficulty,
+    pub language: TextLanguage,
+    pub word_count: i32,
+    pub revision: i32,
+    pub status: PassageStatus,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,50 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "race_replays")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub tournament_id: String,
+    pub member_id: String,
+    pub user_id: Option<String>,
+    pub username: Option<String>,
+    pub started_at: Option<DateTimeWithTimeZone>,
+    pub event_count: i32,
+    #[sea_orm(column_type = "Blob")]
+    pub events: Vec<u8>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::tournaments::Entity",
+        from = "Column::TournamentId",
+        to = "super::tournaments::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Tournaments,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Users,
+}
+
+impl Related<super::tournaments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tournaments.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Users.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,31 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "tournament_bans")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub tournament_id: String,
+    pub member_id: String,
+    pub banned_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::tournaments::Entity",
+        from = "Column::TournamentId",
+        to = "super::tournaments::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Tournaments,
+}
+
+impl Related<super::tournaments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tournaments.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
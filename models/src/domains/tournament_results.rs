@@ -0,0 +1,56 @@
+use super::sea_orm_active_enums::RaceOutcome;
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "tournament_results")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub tournament_id: String,
+    pub member_id: String,
+    pub user_id: Option<String>,
+    pub username: Option<String>,
+    pub rank: i32,
+    pub speed: i32,
+    pub accuracy: i32,
+    pub progress: i32,
+    pub started_at: Option<DateTimeWithTimeZone>,
+    pub ended_at: Option<DateTimeWithTimeZone>,
+    pub duration_ms: Option<i64>,
+    pub outcome: RaceOutcome,
+    pub team: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::tournaments::Entity",
+        from = "Column::TournamentId",
+        to = "super::tournaments::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Tournaments,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Users,
+}
+
+impl Related<super::tournaments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tournaments.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Users.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,37 @@
+use sea_orm::entity::prelude::*;
+
+/// How one stretch of a tournament's texts was generated: from the tournament's
+/// seed alone, or from the passage revision it drew.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "tournament_texts")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub tournament_id: String,
+    pub round: i32,
+    /// Characters into the round's text the stretch starts, non-zero only for
+    /// the later stretches of time-attack texts.
+    pub text_offset: i32,
+    pub passage_id: Option<i32>,
+    pub passage_revision: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::tournaments::Entity",
+        from = "Column::TournamentId",
+        to = "super::tournaments::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Tournaments,
+}
+
+impl Related<super::tournaments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tournaments.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,4 +1,4 @@
-use super::sea_orm_active_enums::TournamentPrivacy;
+use super::sea_orm_active_enums::{RaceMode, TeamScoring, TextLanguage, TournamentPrivacy};
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
@@ -16,11 +16,50 @@ pub struct Model {
     pub privacy: TournamentPrivacy,
     #[sea_orm(column_type = "JsonBinary", nullable)]
     pub text_options: Option<Json>,
+    /// Legacy, no longer written: the last round's text of tournaments created
+    /// before `text_seed`, which can't be regenerated.
+    #[sea_orm(column_type = "Text", nullable)]
+    pub text: Option<String>,
+    pub invite_code: Option<String>,
+    pub race_duration_secs: i32,
+    pub join_cutoff_secs: i32,
+    pub inactivity_timeout_secs: i32,
+    pub max_participants: Option<i32>,
+    pub team_scoring: Option<TeamScoring>,
+    pub rounds: i32,
+    pub eliminations_per_round: i32,
+    pub series_id: Option<String>,
+    pub practice: bool,
+    pub mode: RaceMode,
+    pub language: TextLanguage,
+    pub text_seed: Option<i64>,
     pub updated_at: DateTimeWithTimeZone,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::race_replays::Entity")]
+    RaceReplays,
+    #[sea_orm(has_many = "super::rating_history::Entity")]
+    RatingHistory,
+    #[sea_orm(has_many = "super::tournament_bans::Entity")]
+    TournamentBans,
+    #[sea_orm(has_many = "super::tournament_invites::Entity")]
+    TournamentInvites,
+    #[sea_orm(has_many = "super::tournament_results::Entity")]
+    TournamentResults,
+    #[sea_orm(
+        belongs_to = "super::tournament_series::Entity",
+        from = "Column::SeriesId",
+        to = "super::tournament_series::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    TournamentSeries,
+    #[sea_orm(has_many = "super::tournament_teams::Entity")]
+    TournamentTeams,
+    #[sea_orm(has_many = "super::tournament_texts::Entity")]
+    TournamentTexts,
     #[sea_orm(has_many = "super::typing_history::Entity")]
     TypingHistorys,
     #[sea_orm(
@@ -33,6 +72,54 @@ pub enum Relation {
     Users,
 }
 
+impl Related<super::race_replays::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RaceReplays.def()
+    }
+}
+
+impl Related<super::rating_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RatingHistory.def()
+    }
+}
+
+impl Related<super::tournament_bans::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TournamentBans.def()
+    }
+}
+
+impl Related<super::tournament_invites::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TournamentInvites.def()
+    }
+}
+
+impl Related<super::tournament_results::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TournamentResults.def()
+    }
+}
+
+impl Related<super::tournament_series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TournamentSeries.def()
+    }
+}
+
+impl Related<super::tournament_teams::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TournamentTeams.def()
+    }
+}
+
+impl Related<super::tournament_texts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TournamentTexts.def()
+    }
+}
+
 impl Related<super::typing_history::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::TypingHistorys.def()
@@ -1,3 +1,4 @@
+use super::sea_orm_active_enums::RaceOutcome;
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
@@ -10,6 +11,10 @@ pub struct Model {
     pub accuracy: i32,
     pub speed: i32,
     pub completed_at: DateTimeWithTimeZone,
+    pub placement: i32,
+    pub duration_ms: Option<i64>,
+    pub outcome: RaceOutcome,
+    pub anonymous: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -1,3 +1,4 @@
+use super::sea_orm_active_enums::UserRole;
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
@@ -10,18 +11,61 @@ pub struct Model {
     #[sea_orm(unique)]
     pub email: String,
     pub passhash: Option<String>,
+    pub role: UserRole,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::key_stats::Entity")]
+    KeyStats,
+    #[sea_orm(has_many = "super::rating_history::Entity")]
+    RatingHistory,
+    #[sea_orm(has_one = "super::ratings::Entity")]
+    Ratings,
+    #[sea_orm(has_many = "super::tournament_invites::Entity")]
+    TournamentInvites,
+    #[sea_orm(has_many = "super::tournament_series::Entity")]
+    TournamentSeries,
     #[sea_orm(has_many = "super::typing_history::Entity")]
     TypingHistorys,
+    #[sea_orm(has_many = "super::user_achievements::Entity")]
+    UserAchievements,
     #[sea_orm(has_many = "super::tournaments::Entity")]
     Tournaments,
 }
 
+impl Related<super::key_stats::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::KeyStats.def()
+    }
+}
+
+impl Related<super::rating_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RatingHistory.def()
+    }
+}
+
+impl Related<super::ratings::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Ratings.def()
+    }
+}
+
+impl Related<super::tournament_invites::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TournamentInvites.def()
+    }
+}
+
+impl Related<super::tournament_series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TournamentSeries.def()
+    }
+}
+
 impl Related<super::typing_history::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::TypingHistorys.def()
@@ -34,4 +78,10 @@ impl Related<super::tournaments::Entity> for Entity {
     }
 }
 
+impl Related<super::user_achievements::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserAchievements.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
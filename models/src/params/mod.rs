@@ -1,3 +1,17 @@
+use serde::{Deserialize, Deserializer};
+
 pub mod otp;
+pub mod passage;
+pub mod series;
 pub mod tournament;
 pub mod user;
+
+/// Tells a field left out, deserialized as `None`, from one set to `null`, deserialized
+/// as `Some(None)`, so an update can clear it. Use together with `#[serde(default)]`.
+pub(crate) fn double_option<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
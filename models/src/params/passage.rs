@@ -0,0 +1,42 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::domains::sea_orm_active_enums::{TextDifficulty, TextLanguage};
+
+pub const MAX_PASSAGE_LENGTH: u64 = 5000;
+pub const MAX_PASSAGE_TAGS: u64 = 16;
+
+#[derive(Deserialize, Validate, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePassageParams {
+    #[validate(length(min = 1, max = MAX_PASSAGE_LENGTH))]
+    pub text: String,
+    /// Where the passage comes from, e.g. the book, speech or film it is quoted from.
+    #[validate(length(min = 1, max = 255))]
+    pub source: String,
+    #[validate(length(min = 1, max = 255))]
+    pub author: Option<String>,
+    #[validate(length(max = MAX_PASSAGE_TAGS))]
+    pub tags: Option<Vec<String>>,
+    /// Estimated from the text when left out.
+    pub difficulty: Option<TextDifficulty>,
+    /// English when left out.
+    pub language: Option<TextLanguage>,
+}
+
+#[derive(Deserialize, Validate, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePassageParams {
+    /// A new text is sent back for approval.
+    #[validate(length(min = 1, max = MAX_PASSAGE_LENGTH))]
+    pub text: Option<String>,
+    #[validate(length(min = 1, max = 255))]
+    pub source: Option<String>,
+    #[validate(length(min = 1, max = 255))]
+    pub author: Option<String>,
+    #[validate(length(max = MAX_PASSAGE_TAGS))]
+    pub tags: Option<Vec<String>>,
+    pub difficulty: Option<TextDifficulty>,
+    pub language: Option<TextLanguage>,
+}
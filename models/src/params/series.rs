@@ -0,0 +1,27 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::schemas::{series::RecurrenceRule, typing::TextOptions};
+
+use super::tournament::{
+    MAX_INACTIVITY_TIMEOUT_SECS, MAX_JOIN_CUTOFF_SECS, MAX_RACE_DURATION_SECS,
+    MIN_INACTIVITY_TIMEOUT_SECS, MIN_JOIN_CUTOFF_SECS, MIN_RACE_DURATION_SECS,
+};
+
+#[derive(Deserialize, Validate, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSeriesParams {
+    pub title: String,
+    pub description: String,
+    #[validate(nested)]
+    pub recurrence: RecurrenceRule,
+    #[validate(nested)]
+    pub text_options: Option<TextOptions>,
+    #[validate(range(min = MIN_RACE_DURATION_SECS, max = MAX_RACE_DURATION_SECS))]
+    pub race_duration_secs: Option<i32>,
+    #[validate(range(min = MIN_JOIN_CUTOFF_SECS, max = MAX_JOIN_CUTOFF_SECS))]
+    pub join_cutoff_secs: Option<i32>,
+    #[validate(range(min = MIN_INACTIVITY_TIMEOUT_SECS, max = MAX_INACTIVITY_TIMEOUT_SECS))]
+    pub inactivity_timeout_secs: Option<i32>,
+}
@@ -3,7 +3,40 @@ use serde::Deserialize;
 use utoipa::ToSchema;
 use validator::Validate;
 
-use crate::schemas::typing::TextOptions;
+use super::double_option;
+use crate::{
+    domains::sea_orm_active_enums::{RaceMode, TeamScoring, TournamentPrivacy},
+    schemas::typing::TextOptions,
+};
+
+// Per-tournament timing defaults and the bounds creators may choose from, in seconds.
+pub const DEFAULT_RACE_DURATION_SECS: i32 = 600;
+pub const MIN_RACE_DURATION_SECS: i32 = 30;
+pub const MAX_RACE_DURATION_SECS: i32 = 3600;
+
+pub const DEFAULT_JOIN_CUTOFF_SECS: i32 = 15;
+pub const MIN_JOIN_CUTOFF_SECS: i32 = 0;
+pub const MAX_JOIN_CUTOFF_SECS: i32 = 600;
+
+pub const DEFAULT_INACTIVITY_TIMEOUT_SECS: i32 = 30;
+pub const MIN_INACTIVITY_TIMEOUT_SECS: i32 = 10;
+pub const MAX_INACTIVITY_TIMEOUT_SECS: i32 = 300;
+
+// Bounds for the optional participant cap.
+pub const MIN_PARTICIPANT_LIMIT: i32 = 1;
+pub const MAX_PARTICIPANT_LIMIT: i32 = 1000;
+
+pub const MAX_TEAM_NAME_LENGTH: usize = 32;
+
+// Elimination brackets: how many races a tournament runs and how many members drop
+// out after each of them.
+pub const DEFAULT_ROUNDS: i32 = 1;
+pub const MIN_ROUNDS: i32 = 1;
+pub const MAX_ROUNDS: i32 = 10;
+
+pub const DEFAULT_ELIMINATIONS_PER_ROUND: i32 = 1;
+pub const MIN_ELIMINATIONS_PER_ROUND: i32 = 1;
+pub const MAX_ELIMINATIONS_PER_ROUND: i32 = 100;
 
 #[derive(Deserialize, Validate, Debug, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -11,16 +44,75 @@ pub struct CreateTournamentParams {
     pub title: String,
     pub description: String,
     pub scheduled_for: DateTime<FixedOffset>,
+    #[validate(nested)]
     pub text_options: Option<TextOptions>,
+    pub privacy: Option<TournamentPrivacy>,
+    #[validate(range(min = MIN_RACE_DURATION_SECS, max = MAX_RACE_DURATION_SECS))]
+    pub race_duration_secs: Option<i32>,
+    #[validate(range(min = MIN_JOIN_CUTOFF_SECS, max = MAX_JOIN_CUTOFF_SECS))]
+    pub join_cutoff_secs: Option<i32>,
+    #[validate(range(min = MIN_INACTIVITY_TIMEOUT_SECS, max = MAX_INACTIVITY_TIMEOUT_SECS))]
+    pub inactivity_timeout_secs: Option<i32>,
+    #[validate(range(min = MIN_PARTICIPANT_LIMIT, max = MAX_PARTICIPANT_LIMIT))]
+    pub max_participants: Option<i32>,
+    pub team_scoring: Option<TeamScoring>,
+    #[validate(range(min = MIN_ROUNDS, max = MAX_ROUNDS))]
+    pub rounds: Option<i32>,
+    #[validate(range(min = MIN_ELIMINATIONS_PER_ROUND, max = MAX_ELIMINATIONS_PER_ROUND))]
+    pub eliminations_per_round: Option<i32>,
+    pub mode: Option<RaceMode>,
+    /// Races the texts of an ended tournament of the caller's again. Its text
+    /// options, mode and rounds are taken over, whatever is passed here.
+    pub rematch_of: Option<String>,
 }
 
 #[derive(Deserialize, Validate, Debug, ToSchema, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateTournamentParams {
+    #[serde(skip)]
     pub id: Option<String>,
     pub title: Option<String>,
     pub description: Option<String>,
     pub scheduled_for: Option<DateTime<FixedOffset>>,
+    /// `null` goes back to the default text options.
+    #[serde(default, deserialize_with = "double_option")]
+    #[validate(nested)]
     pub text_options: Option<Option<TextOptions>>,
+    pub privacy: Option<TournamentPrivacy>,
+    #[validate(range(min = MIN_RACE_DURATION_SECS, max = MAX_RACE_DURATION_SECS))]
+    pub race_duration_secs: Option<i32>,
+    #[validate(range(min = MIN_JOIN_CUTOFF_SECS, max = MAX_JOIN_CUTOFF_SECS))]
+    pub join_cutoff_secs: Option<i32>,
+    #[validate(range(min = MIN_INACTIVITY_TIMEOUT_SECS, max = MAX_INACTIVITY_TIMEOUT_SECS))]
+    pub inactivity_timeout_secs: Option<i32>,
+    /// `null` removes the participant limit.
+    #[serde(default, deserialize_with = "double_option")]
+    #[validate(range(min = MIN_PARTICIPANT_LIMIT, max = MAX_PARTICIPANT_LIMIT))]
+    pub max_participants: Option<Option<i32>>,
+    /// `null` turns a team tournament back into one raced alone.
+    #[serde(default, deserialize_with = "double_option")]
+    pub team_scoring: Option<Option<TeamScoring>>,
+    #[validate(range(min = MIN_ROUNDS, max = MAX_ROUNDS))]
+    pub rounds: Option<i32>,
+    #[validate(range(min = MIN_ELIMINATIONS_PER_ROUND, max = MAX_ELIMINATIONS_PER_ROUND))]
+    pub eliminations_per_round: Option<i32>,
+    pub mode: Option<RaceMode>,
+    #[serde(skip)]
     pub ended_at: Option<Option<DateTime<FixedOffset>>>,
 }
+
+#[derive(Deserialize, Validate, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteUsersParams {
+    #[validate(length(min = 1, max = 100))]
+    pub user_ids: Vec<String>,
+}
+
+#[derive(Deserialize, Validate, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KickMemberParams {
+    #[validate(length(min = 1, max = 64))]
+    pub member_id: String,
+    /// Also bans the member from joining again.
+    pub ban: Option<bool>,
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, Default, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+#[serde(rename_all = "camelCase")]
+pub struct WeakKeysQuery {
+    /// Keys and bigrams attempted fewer times than this are left out.
+    pub min_attempts: Option<u64>,
+    /// Maximum number of keys and of bigrams returned.
+    pub limit: Option<u64>,
+}
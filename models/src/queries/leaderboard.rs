@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LeaderboardPeriod {
+    Daily,
+    Weekly,
+    Monthly,
+    #[default]
+    AllTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LeaderboardMetric {
+    #[default]
+    Best,
+    Average,
+}
+
+#[derive(Deserialize, Default, IntoParams, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct LeaderboardQuery {
+    pub period: Option<LeaderboardPeriod>,
+    pub metric: Option<LeaderboardMetric>,
+    /// Races below this accuracy (in percent) are ignored.
+    pub min_accuracy: Option<i32>,
+    pub limit: Option<u64>,
+}
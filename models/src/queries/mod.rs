@@ -1,8 +1,16 @@
 use serde::Deserialize;
 use utoipa::{IntoParams, ToSchema};
 
-use crate::{domains::sea_orm_active_enums::TournamentPrivacy, schemas::typing::TournamentStatus};
+use crate::{
+    domains::sea_orm_active_enums::{TextLanguage, TournamentPrivacy},
+    schemas::typing::TournamentStatus,
+};
 
+pub mod key_stats;
+pub mod leaderboard;
+pub mod passage;
+pub mod stats;
+pub mod typing_history;
 pub mod user;
 
 #[derive(Deserialize, IntoParams, ToSchema)]
@@ -12,6 +20,8 @@ pub struct TournamentPaginationQuery {
     pub limit: Option<u64>,
     pub privacy: Option<TournamentPrivacy>,
     pub status: Option<TournamentStatus>,
+    /// Only tournaments raced in this language.
+    pub language: Option<TextLanguage>,
     pub search: Option<String>,
 }
 
@@ -22,6 +32,7 @@ impl Default for TournamentPaginationQuery {
             limit: Some(15),
             privacy: None,
             status: None,
+            language: None,
             search: None,
         }
     }
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domains::sea_orm_active_enums::{PassageStatus, TextDifficulty, TextLanguage};
+
+#[derive(Deserialize, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct PassageQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+    pub status: Option<PassageStatus>,
+    pub difficulty: Option<TextDifficulty>,
+    pub language: Option<TextLanguage>,
+    /// Only passages carrying this tag.
+    pub tag: Option<String>,
+}
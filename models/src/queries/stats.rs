@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, Default, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct UserStatsQuery {
+    /// Number of most recent finished races used for the trend.
+    pub recent: Option<u64>,
+}
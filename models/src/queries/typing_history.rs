@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, Clone, Copy, Debug, Default, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, Default, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TypingHistorySortBy {
+    #[default]
+    Date,
+    Speed,
+    Accuracy,
+}
+
+#[derive(Deserialize, IntoParams, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct TypingHistoryQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub min_speed: Option<i32>,
+    pub max_speed: Option<i32>,
+    pub sort_by: Option<TypingHistorySortBy>,
+    pub order: Option<SortOrder>,
+}
+
+impl Default for TypingHistoryQuery {
+    fn default() -> Self {
+        Self {
+            page: Some(1),
+            limit: Some(20),
+            from: None,
+            to: None,
+            min_speed: None,
+            max_speed: None,
+            sort_by: None,
+            order: None,
+        }
+    }
+}
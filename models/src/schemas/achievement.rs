@@ -0,0 +1,13 @@
+use sea_orm::prelude::DateTimeUtc;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AchievementSchema {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    #[schema(value_type = String, format = DateTime)]
+    pub unlocked_at: DateTimeUtc,
+}
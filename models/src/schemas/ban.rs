@@ -0,0 +1,18 @@
+use sea_orm::prelude::DateTimeUtc;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BannedMemberSchema {
+    pub member_id: String,
+    #[schema(value_type = String, format = DateTime)]
+    pub banned_at: DateTimeUtc,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentBansSchema {
+    pub tournament_id: String,
+    pub bans: Vec<BannedMemberSchema>,
+}
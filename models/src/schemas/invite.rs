@@ -0,0 +1,20 @@
+use sea_orm::prelude::DateTimeUtc;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteeSchema {
+    pub user_id: String,
+    pub username: String,
+    #[schema(value_type = String, format = DateTime)]
+    pub invited_at: DateTimeUtc,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentInvitesSchema {
+    pub tournament_id: String,
+    pub invite_code: Option<String>,
+    pub invitees: Vec<InviteeSchema>,
+}
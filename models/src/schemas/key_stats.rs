@@ -0,0 +1,24 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyErrorSchema {
+    /// The expected character, or the preceding and expected characters for a bigram.
+    pub sequence: String,
+    pub attempts: i64,
+    pub errors: i64,
+    /// Share of attempts that were mistyped, from 0 to 1.
+    pub error_rate: f32,
+    pub average_latency_ms: Option<f32>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WeakKeysSchema {
+    pub user_id: String,
+    /// Keys ordered by error rate, worst first.
+    pub keys: Vec<KeyErrorSchema>,
+    /// Bigrams ordered by error rate, worst first.
+    pub bigrams: Vec<KeyErrorSchema>,
+}
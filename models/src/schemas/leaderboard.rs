@@ -0,0 +1,27 @@
+use sea_orm::prelude::DateTimeUtc;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::queries::leaderboard::{LeaderboardMetric, LeaderboardPeriod};
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardEntrySchema {
+    pub rank: usize,
+    pub user_id: String,
+    pub username: String,
+    /// Best or average WPM depending on the board's metric.
+    pub score: f32,
+    pub races: i64,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardSchema {
+    pub period: LeaderboardPeriod,
+    pub metric: LeaderboardMetric,
+    pub min_accuracy: i32,
+    #[schema(value_type = String, format = DateTime)]
+    pub generated_at: DateTimeUtc,
+    pub entries: Vec<LeaderboardEntrySchema>,
+}
@@ -1,4 +1,14 @@
+pub mod achievement;
+pub mod ban;
+pub mod invite;
+pub mod key_stats;
+pub mod leaderboard;
 pub mod pagination;
+pub mod passage;
+pub mod rating;
+pub mod replay;
+pub mod series;
+pub mod stats;
 pub mod tournament;
 pub mod typing;
 pub mod typing_history;
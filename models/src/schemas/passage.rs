@@ -0,0 +1,47 @@
+use sea_orm::prelude::DateTimeUtc;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domains::{
+    passages,
+    sea_orm_active_enums::{PassageStatus, TextDifficulty, TextLanguage},
+};
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PassageSchema {
+    pub id: i32,
+    pub text: String,
+    pub source: String,
+    pub author: Option<String>,
+    pub tags: Vec<String>,
+    pub difficulty: TextDifficulty,
+    pub language: TextLanguage,
+    pub word_count: i32,
+    /// Counts the edits to the text, so races can tell which text they drew.
+    pub revision: i32,
+    pub status: PassageStatus,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTimeUtc,
+    #[schema(value_type = String, format = DateTime)]
+    pub updated_at: DateTimeUtc,
+}
+
+impl From<passages::Model> for PassageSchema {
+    fn from(passage: passages::Model) -> Self {
+        Self {
+            id: passage.id,
+            text: passage.text,
+            source: passage.source,
+            author: passage.author,
+            tags: serde_json::from_value(passage.tags).unwrap_or_default(),
+            difficulty: passage.difficulty,
+            language: passage.language,
+            word_count: passage.word_count,
+            revision: passage.revision,
+            status: passage.status,
+            created_at: passage.created_at.to_utc(),
+            updated_at: passage.updated_at.to_utc(),
+        }
+    }
+}
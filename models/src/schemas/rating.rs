@@ -0,0 +1,39 @@
+use sea_orm::prelude::DateTimeUtc;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domains::rating_history;
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RatingHistorySchema {
+    pub tournament_id: String,
+    pub rating_before: i32,
+    pub rating_after: i32,
+    pub placement: i32,
+    #[schema(value_type = String, format = DateTime)]
+    pub recorded_at: DateTimeUtc,
+}
+
+impl From<rating_history::Model> for RatingHistorySchema {
+    fn from(entry: rating_history::Model) -> Self {
+        Self {
+            tournament_id: entry.tournament_id,
+            rating_before: entry.rating_before,
+            rating_after: entry.rating_after,
+            placement: entry.placement,
+            recorded_at: entry.recorded_at.to_utc(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRatingSchema {
+    pub user_id: String,
+    pub rating: i32,
+    /// Number of rated tournaments played; zero means the rating is the starting value.
+    pub games: i32,
+    /// Most recent rating changes, newest first.
+    pub history: Vec<RatingHistorySchema>,
+}
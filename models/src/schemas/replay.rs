@@ -0,0 +1,38 @@
+use sea_orm::prelude::DateTimeUtc;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::user::TournamentRoomMember;
+
+/// One step of a participant's race, in the order the server processed it.
+///
+/// `keys` holds the characters received in that step, with `\u{8}` for backspace.
+/// It is empty for clients that only report progress.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayEventSchema {
+    /// Milliseconds since the participant's first keystroke.
+    pub offset_ms: u64,
+    pub keys: String,
+    pub current_position: usize,
+    pub correct_position: usize,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayTrackSchema {
+    pub member: TournamentRoomMember,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub started_at: Option<DateTimeUtc>,
+    pub events: Vec<ReplayEventSchema>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RaceReplaySchema {
+    pub tournament_id: String,
+    pub text: Option<String>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub started_at: Option<DateTimeUtc>,
+    pub tracks: Vec<ReplayTrackSchema>,
+}
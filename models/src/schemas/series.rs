@@ -0,0 +1,122 @@
+use chrono::Weekday;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::domains::tournament_series;
+
+use super::{tournament::TournamentSchema, typing::TextOptions};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RecurrenceDay {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl From<RecurrenceDay> for Weekday {
+    fn from(day: RecurrenceDay) -> Self {
+        match day {
+            RecurrenceDay::Monday => Weekday::Mon,
+            RecurrenceDay::Tuesday => Weekday::Tue,
+            RecurrenceDay::Wednesday => Weekday::Wed,
+            RecurrenceDay::Thursday => Weekday::Thu,
+            RecurrenceDay::Friday => Weekday::Fri,
+            RecurrenceDay::Saturday => Weekday::Sat,
+            RecurrenceDay::Sunday => Weekday::Sun,
+        }
+    }
+}
+
+/// When a series runs: at `hour:minute` UTC on each of `days`, or every day
+/// when `days` is empty.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurrenceRule {
+    #[serde(default)]
+    pub days: Vec<RecurrenceDay>,
+    #[validate(range(max = 23))]
+    pub hour: u32,
+    #[validate(range(max = 59))]
+    pub minute: u32,
+}
+
+impl RecurrenceRule {
+    pub fn from_value(value: serde_json::Value) -> Self {
+        serde_json::from_value(value).unwrap_or_default()
+    }
+
+    pub fn to_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+
+    pub fn runs_on(&self, weekday: Weekday) -> bool {
+        self.days.is_empty() || self.days.iter().any(|&day| Weekday::from(day) == weekday)
+    }
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesSchema {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub created_by: String,
+    pub recurrence: RecurrenceRule,
+    pub text_options: Option<TextOptions>,
+    pub race_duration_secs: i32,
+    pub join_cutoff_secs: i32,
+    pub inactivity_timeout_secs: i32,
+    pub active: bool,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTimeUtc,
+    /// Materialised tournaments of the series that have not ended yet.
+    pub upcoming: Vec<TournamentSchema>,
+}
+
+impl SeriesSchema {
+    pub fn new(series: tournament_series::Model, upcoming: Vec<TournamentSchema>) -> Self {
+        Self {
+            id: series.id,
+            title: series.title,
+            description: series.description,
+            created_by: series.created_by,
+            recurrence: RecurrenceRule::from_value(series.recurrence),
+            text_options: series.text_options.map(TextOptions::from_value),
+            race_duration_secs: series.race_duration_secs,
+            join_cutoff_secs: series.join_cutoff_secs,
+            inactivity_timeout_secs: series.inactivity_timeout_secs,
+            active: series.active,
+            created_at: series.created_at.to_utc(),
+            upcoming,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesStandingSchema {
+    pub rank: usize,
+    pub user_id: String,
+    pub username: String,
+    pub races: i64,
+    pub wins: i64,
+    pub best_speed: i32,
+    pub average_speed: f32,
+    pub average_accuracy: f32,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesResultsSchema {
+    pub series_id: String,
+    /// Number of tournaments in the series that have ended.
+    pub tournaments: u64,
+    pub standings: Vec<SeriesStandingSchema>,
+}
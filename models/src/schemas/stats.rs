@@ -0,0 +1,36 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeedPercentiles {
+    pub p25: i32,
+    pub p50: i32,
+    pub p75: i32,
+    pub p90: i32,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeedTrend {
+    /// Speeds of the most recent finished races, oldest first.
+    pub speeds: Vec<i32>,
+    pub average_speed: f32,
+    /// Least-squares slope over `speeds`, in WPM gained per race.
+    pub slope: f32,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserStatsSchema {
+    pub user_id: String,
+    pub races_entered: u64,
+    pub races_completed: u64,
+    pub wins: u64,
+    pub win_rate: f32,
+    pub average_speed: f32,
+    pub best_speed: i32,
+    pub average_accuracy: f32,
+    pub speed_percentiles: Option<SpeedPercentiles>,
+    pub trend: SpeedTrend,
+}
@@ -3,9 +3,15 @@ use sea_orm::prelude::DateTimeUtc;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::domains::{sea_orm_active_enums::TournamentPrivacy, tournaments};
+use crate::domains::{
+    sea_orm_active_enums::{RaceMode, RaceOutcome, TeamScoring, TournamentPrivacy},
+    tournament_results, tournament_teams, tournaments,
+};
 
-use super::typing::TextOptions;
+use super::{
+    typing::TextOptions,
+    user::{TournamentRoomMember, TournamentRoomUserProfile},
+};
 
 #[derive(Serialize, Clone, Debug, ToSchema)]
 pub struct TournamentSchema {
@@ -23,6 +29,20 @@ pub struct TournamentSchema {
     pub ended_at: Option<DateTimeUtc>,
     pub privacy: TournamentPrivacy,
     pub text_options: Option<TextOptions>,
+    pub race_duration_secs: i32,
+    pub join_cutoff_secs: i32,
+    pub inactivity_timeout_secs: i32,
+    pub max_participants: Option<i32>,
+    pub team_scoring: Option<TeamScoring>,
+    pub rounds: i32,
+    pub eliminations_per_round: i32,
+    pub series_id: Option<String>,
+    /// Set on the record of a solo practice session.
+    pub practice: bool,
+    pub mode: RaceMode,
+    /// Kept from clients, who could otherwise work out the texts before the race.
+    #[serde(skip)]
+    pub text_seed: Option<i64>,
 }
 
 impl From<tournaments::Model> for TournamentSchema {
@@ -38,6 +58,17 @@ impl From<tournaments::Model> for TournamentSchema {
             ended_at: tournament.ended_at.map(|v| v.to_utc()),
             privacy: tournament.privacy,
             text_options: tournament.text_options.map(TextOptions::from_value),
+            race_duration_secs: tournament.race_duration_secs,
+            join_cutoff_secs: tournament.join_cutoff_secs,
+            inactivity_timeout_secs: tournament.inactivity_timeout_secs,
+            max_participants: tournament.max_participants,
+            team_scoring: tournament.team_scoring,
+            rounds: tournament.rounds,
+            eliminations_per_round: tournament.eliminations_per_round,
+            series_id: tournament.series_id,
+            practice: tournament.practice,
+            mode: tournament.mode,
+            text_seed: tournament.text_seed,
         }
     }
 }
@@ -92,12 +123,22 @@ pub struct Tournament {
     pub description: String,
     pub privacy: TournamentPrivacy,
     pub text_options: Option<TextOptions>,
+    pub race_duration_secs: i32,
+    pub join_cutoff_secs: i32,
+    pub inactivity_timeout_secs: i32,
+    pub max_participants: Option<i32>,
+    pub team_scoring: Option<TeamScoring>,
+    pub rounds: i32,
+    pub eliminations_per_round: i32,
+    pub series_id: Option<String>,
+    pub mode: RaceMode,
     #[schema(value_type = Option<String>, format = DateTime)]
     pub started_at: Option<DateTimeUtc>,
     #[schema(value_type = Option<String>, format = DateTime)]
     pub ended_at: Option<DateTimeUtc>,
     pub participating: bool,
     pub participant_count: usize,
+    pub waitlist_position: Option<usize>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -105,8 +146,93 @@ pub struct Tournament {
 pub struct TournamentLiveData {
     pub participant_count: usize,
     pub participating: bool,
+    /// 1-based position on the waitlist, if the member is waiting for a seat.
+    pub waitlist_position: Option<usize>,
     #[schema(value_type = Option<String>, format = DateTime)]
     pub started_at: Option<DateTimeUtc>,
     #[schema(value_type = Option<String>, format = DateTime)]
     pub ended_at: Option<DateTimeUtc>,
 }
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingSchema {
+    pub rank: i32,
+    pub member: TournamentRoomMember,
+    pub speed: i32,
+    pub accuracy: i32,
+    pub progress: i32,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub finished_at: Option<DateTimeUtc>,
+    pub duration_ms: Option<i64>,
+    pub outcome: RaceOutcome,
+}
+
+impl From<tournament_results::Model> for StandingSchema {
+    fn from(result: tournament_results::Model) -> Self {
+        let finished = result.outcome == RaceOutcome::Finished;
+        Self {
+            rank: result.rank,
+            member: TournamentRoomMember {
+                id: result.member_id,
+                user: result
+                    .username
+                    .map(|username| TournamentRoomUserProfile { username }),
+                participant: true,
+                rating: None,
+                user_id: None,
+                team: result.team,
+            },
+            speed: result.speed,
+            accuracy: result.accuracy,
+            progress: result.progress,
+            finished_at: result.ended_at.filter(|_| finished).map(|v| v.to_utc()),
+            duration_ms: result.duration_ms.filter(|_| finished),
+            outcome: result.outcome,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentResultsSchema {
+    pub tournament_id: String,
+    #[schema(value_type = String, format = DateTime)]
+    pub ended_at: DateTimeUtc,
+    /// Regenerates the tournament's texts, or races them again as a rematch.
+    /// `None` for tournaments created before seeds were stored.
+    pub text_seed: Option<i64>,
+    /// The text raced in each round, regenerated from `text_seed`.
+    pub texts: Vec<RoundTextSchema>,
+    pub standings: Vec<StandingSchema>,
+    pub teams: Vec<TeamStandingSchema>,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundTextSchema {
+    pub round: i32,
+    /// `None` once a passage the text was drawn from has been edited.
+    pub text: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamStandingSchema {
+    pub rank: i32,
+    pub name: String,
+    /// Sum or average of the members' WPM, depending on the tournament's `team_scoring`.
+    pub score: i32,
+    pub member_count: i32,
+}
+
+impl From<tournament_teams::Model> for TeamStandingSchema {
+    fn from(team: tournament_teams::Model) -> Self {
+        Self {
+            rank: team.rank,
+            name: team.name,
+            score: team.score,
+            member_count: team.member_count,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use validator::Validate;
 
+use crate::domains::sea_orm_active_enums::{TextDifficulty, TextLanguage};
 use crate::schemas::user::TournamentRoomMember;
 
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
@@ -15,6 +17,7 @@ pub struct TypingSessionSchema {
     pub total_keystrokes: i32,
     pub current_accuracy: f32,
     pub current_speed: f32,
+    pub timed_out: bool,
 }
 
 impl TypingSessionSchema {
@@ -29,6 +32,7 @@ impl TypingSessionSchema {
             total_keystrokes: 0,
             current_accuracy: 100.0,
             current_speed: 0.0,
+            timed_out: false,
         }
     }
 
@@ -56,13 +60,29 @@ pub enum TournamentStatus {
     Ended,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+// Bounds for the number of words in a generated text.
+pub const DEFAULT_WORD_COUNT: u32 = 24;
+pub const MIN_WORD_COUNT: u32 = 5;
+pub const MAX_WORD_COUNT: u32 = 500;
+
+/// What a generated text is made of. `uppercase` and `lowercase` pick the letter
+/// case, `numbers` mixes in numerals, `symbols` adds punctuation and `meaningful`
+/// draws real-world passages, or dictionary words, instead of random letters.
+/// `difficulty` only narrows down the passages drawn, while `language` picks both
+/// the passages and the dictionary.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TextOptions {
-    uppercase: bool,
-    lowercase: bool,
-    numbers: bool,
-    symbols: bool,
-    meaningful: bool,
+    pub uppercase: bool,
+    pub lowercase: bool,
+    pub numbers: bool,
+    pub symbols: bool,
+    pub meaningful: bool,
+    #[validate(range(min = MIN_WORD_COUNT, max = MAX_WORD_COUNT))]
+    pub word_count: Option<u32>,
+    pub difficulty: Option<TextDifficulty>,
+    #[serde(default)]
+    pub language: TextLanguage,
 }
 
 impl TextOptions {
@@ -83,6 +103,9 @@ impl Default for TextOptions {
             numbers: true,
             symbols: true,
             meaningful: true,
+            word_count: None,
+            difficulty: None,
+            language: TextLanguage::En,
         }
     }
 }
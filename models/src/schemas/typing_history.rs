@@ -2,7 +2,7 @@ use sea_orm::prelude::DateTimeUtc;
 use serde::Serialize;
 use utoipa::ToSchema;
 
-use crate::domains::typing_history;
+use crate::domains::{sea_orm_active_enums::RaceOutcome, typing_history};
 
 #[derive(Serialize, ToSchema)]
 pub struct TypingHistorySchema {
@@ -13,6 +13,10 @@ pub struct TypingHistorySchema {
     pub speed: i32,
     #[schema(value_type = String, format = DateTime)]
     pub completed_at: DateTimeUtc,
+    pub placement: i32,
+    pub duration_ms: Option<i64>,
+    pub outcome: RaceOutcome,
+    pub anonymous: bool,
 }
 
 impl From<typing_history::Model> for TypingHistorySchema {
@@ -24,6 +28,10 @@ impl From<typing_history::Model> for TypingHistorySchema {
             accuracy: session.accuracy,
             speed: session.speed,
             completed_at: session.completed_at.to_utc(),
+            placement: session.placement,
+            duration_ms: session.duration_ms,
+            outcome: session.outcome,
+            anonymous: session.anonymous,
         }
     }
 }
@@ -26,6 +26,13 @@ pub struct TournamentRoomMember {
     pub id: String,
     pub user: Option<TournamentRoomUserProfile>,
     pub participant: bool,
+    /// Skill rating of registered members, `None` for guests.
+    pub rating: Option<i32>,
+    /// Account backing this member, kept server-side so anonymous members stay anonymous.
+    #[serde(skip)]
+    pub user_id: Option<String>,
+    /// Team the member races for in team tournaments.
+    pub team: Option<String>,
 }
 
 impl TournamentRoomMember {
@@ -40,6 +47,9 @@ impl TournamentRoomMember {
                 })
             },
             participant,
+            rating: None,
+            user_id: Some(user.id.clone()),
+            team: None,
         }
     }
 
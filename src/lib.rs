@@ -1,4 +1,7 @@
 use api::{setup_config, setup_db, setup_router};
+use app::persistence::series::{SERIES_MATERIALISE_INTERVAL, materialise_active_series};
+use app::scheduler::schedule_repeating_task;
+use chrono::Utc;
 
 pub async fn run() {
     let config = setup_config().await;
@@ -12,6 +15,18 @@ pub async fn run() {
 
     utils::migrate(&conn).await.expect("Migration failed!");
 
+    let series_conn = conn.clone();
+    schedule_repeating_task(SERIES_MATERIALISE_INTERVAL, move || {
+        let conn = series_conn.clone();
+        async move {
+            match materialise_active_series(&conn, Utc::now()).await {
+                Ok(0) => {}
+                Ok(created) => tracing::info!("Scheduled {} series tournaments", created),
+                Err(e) => tracing::error!("Failed to schedule series tournaments: {}", e),
+            }
+        }
+    });
+
     let router = setup_router(config, conn);
     let listener = tokio::net::TcpListener::from_std(listener).expect("bind to port");
     axum::serve(listener, router).await.expect("start server");
@@ -1,4 +1,5 @@
-use api::{setup_config, setup_router};
+use api::setup_router;
+use app::config::Config;
 use utils::testing::setup_test_db;
 
 mod openapi;
@@ -13,7 +14,7 @@ async fn root_main() {
     let db = setup_test_db("sqlite::root?mode=memory&cache=shared")
         .await
         .expect("Set up db failed!");
-    let config = setup_config().await;
+    let config = Config::for_tests();
     let app = setup_router(config, db);
     test_root(app).await;
 }
@@ -24,7 +25,7 @@ async fn user_main() {
         .await
         .expect("Set up db failed!");
 
-    let config = setup_config().await;
+    let config = Config::for_tests();
     let app = setup_router(config, db);
     test_post_users(app.clone()).await;
     test_post_users_error(app.clone()).await;
@@ -37,7 +38,7 @@ async fn blog_main() {
         .await
         .expect("Set up db failed!");
 
-    let config = setup_config().await;
+    let config = Config::for_tests();
     let app = setup_router(config, db);
     test_post_users(app.clone()).await;
 }
@@ -0,0 +1,145 @@
+use app::core::KeyStats;
+use app::persistence::achievements::unlock_achievements;
+use app::persistence::practice::record_practice_session;
+use app::persistence::text::GeneratedText;
+use app::persistence::tournaments::create_tournament;
+use app::{persistence::users::create_user, state::AppState};
+use chrono::{TimeDelta, Utc};
+use models::domains::{sea_orm_active_enums::RaceOutcome, typing_history};
+use models::params::tournament::CreateTournamentParams;
+use models::params::user::CreateUserParams;
+use models::schemas::typing::TypingSessionSchema;
+use models::schemas::user::{TournamentRoomMember, UserSchema};
+use sea_orm::{ActiveModelTrait, Set, TryIntoModel};
+
+pub(super) async fn test_achievements(state: &AppState) {
+    let user = UserSchema::from(
+        create_user(
+            state,
+            CreateUserParams {
+                email: "achiever@example.com".to_string(),
+                password: "password".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+        .try_into_model()
+        .unwrap(),
+    );
+
+    let now = Utc::now();
+
+    // A fast, flawless practice run unlocks nothing.
+    let mut practice = TypingSessionSchema::new(
+        TournamentRoomMember::from_user(&user, false, true),
+        "practice0003".to_string(),
+    );
+    practice.started_at = Some(now - TimeDelta::seconds(20));
+    practice.ended_at = Some(now);
+    practice.current_speed = 150.0;
+    practice.current_accuracy = 100.0;
+    record_practice_session(
+        &state.conn,
+        &practice,
+        &GeneratedText {
+            text: "practice text".to_string(),
+            passage: None,
+        },
+        7,
+        KeyStats::default(),
+        now,
+    )
+    .await
+    .expect("Record practice failed!");
+
+    let unlocked = unlock_achievements(&state.conn, &user.id, "practice0003", now)
+        .await
+        .expect("Unlock achievements failed!");
+    assert!(unlocked.is_empty());
+
+    let tournament = create_tournament(
+        &state.conn,
+        CreateTournamentParams {
+            title: "achievements".to_string(),
+            scheduled_for: now.fixed_offset(),
+            description: String::new(),
+            text_options: None,
+            privacy: None,
+            race_duration_secs: None,
+            join_cutoff_secs: None,
+            inactivity_timeout_secs: None,
+            max_participants: None,
+            team_scoring: None,
+            rounds: None,
+            eliminations_per_round: None,
+            mode: None,
+            rematch_of: None,
+        },
+        &user,
+    )
+    .await
+    .expect("Create tournament failed!");
+
+    typing_history::ActiveModel {
+        user_id: Set(user.id.clone()),
+        tournament_id: Set(tournament.id.clone()),
+        accuracy: Set(95),
+        speed: Set(80),
+        completed_at: Set(now.fixed_offset()),
+        placement: Set(2),
+        duration_ms: Set(None),
+        outcome: Set(RaceOutcome::Finished),
+        anonymous: Set(false),
+        ..Default::default()
+    }
+    .insert(&state.conn)
+    .await
+    .expect("Insert typing history failed!");
+
+    // Only the tournament race counts: no 100 WPM badge from the practice run.
+    let unlocked = unlock_achievements(&state.conn, &user.id, &tournament.id, now)
+        .await
+        .expect("Unlock achievements failed!");
+    let ids = unlocked
+        .iter()
+        .map(|achievement| achievement.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["first_race"]);
+
+    // A race on each of the six days before makes a streak of seven. The DNF is
+    // fast and first, yet counts towards neither speed nor wins.
+    let races = [
+        (1, 100, 3, RaceOutcome::Finished),
+        (2, 90, 1, RaceOutcome::Finished),
+        (3, 200, 1, RaceOutcome::Dnf),
+        (4, 70, 2, RaceOutcome::Finished),
+        (5, 70, 2, RaceOutcome::Finished),
+        (6, 70, 2, RaceOutcome::Finished),
+    ];
+    for (days_ago, speed, placement, outcome) in races {
+        typing_history::ActiveModel {
+            user_id: Set(user.id.clone()),
+            tournament_id: Set(tournament.id.clone()),
+            accuracy: Set(100),
+            speed: Set(speed),
+            completed_at: Set((now - TimeDelta::days(days_ago)).fixed_offset()),
+            placement: Set(placement),
+            duration_ms: Set(None),
+            outcome: Set(outcome),
+            anonymous: Set(false),
+            ..Default::default()
+        }
+        .insert(&state.conn)
+        .await
+        .expect("Insert typing history failed!");
+    }
+
+    let unlocked = unlock_achievements(&state.conn, &user.id, &tournament.id, now)
+        .await
+        .expect("Unlock achievements failed!");
+    let ids = unlocked
+        .iter()
+        .map(|achievement| achievement.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["first_win", "wpm_100", "streak_7"]);
+}
@@ -0,0 +1,218 @@
+use app::core::KeyStats;
+use app::persistence::leaderboards::get_leaderboard;
+use app::persistence::practice::record_practice_session;
+use app::persistence::text::GeneratedText;
+use app::persistence::tournaments::create_tournament;
+use app::{persistence::users::create_user, state::AppState};
+use chrono::{TimeDelta, Utc};
+use models::domains::{sea_orm_active_enums::RaceOutcome, typing_history};
+use models::params::tournament::CreateTournamentParams;
+use models::params::user::CreateUserParams;
+use models::queries::leaderboard::{LeaderboardMetric, LeaderboardPeriod, LeaderboardQuery};
+use models::schemas::typing::TypingSessionSchema;
+use models::schemas::user::{TournamentRoomMember, UserSchema};
+use sea_orm::{ActiveModelTrait, Set, TryIntoModel};
+
+/// Boards are shared with the other tests, so only the order of this test's
+/// users is checked, on a minimum accuracy no other test asks for.
+const MIN_ACCURACY: i32 = 93;
+
+async fn leaderboard_user(state: &AppState, email: &str) -> UserSchema {
+    UserSchema::from(
+        create_user(
+            state,
+            CreateUserParams {
+                email: email.to_string(),
+                password: "password".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+        .try_into_model()
+        .unwrap(),
+    )
+}
+
+async fn ranked(
+    state: &AppState,
+    period: LeaderboardPeriod,
+    metric: LeaderboardMetric,
+    users: &[&UserSchema],
+) -> Vec<(String, f32)> {
+    let board = get_leaderboard(
+        state,
+        LeaderboardQuery {
+            period: Some(period),
+            metric: Some(metric),
+            min_accuracy: Some(MIN_ACCURACY),
+            limit: None,
+        },
+    )
+    .await
+    .expect("Get leaderboard failed!");
+
+    assert!(
+        board
+            .entries
+            .iter()
+            .enumerate()
+            .all(|(index, entry)| entry.rank == index + 1)
+    );
+
+    board
+        .entries
+        .into_iter()
+        .filter(|entry| users.iter().any(|user| user.id == entry.user_id))
+        .map(|entry| (entry.user_id, entry.score))
+        .collect()
+}
+
+pub(super) async fn test_leaderboards(state: &AppState) {
+    let fast = leaderboard_user(state, "fast@example.com").await;
+    let steady = leaderboard_user(state, "steady@example.com").await;
+    let tied = leaderboard_user(state, "tied@example.com").await;
+    let sloppy = leaderboard_user(state, "sloppy@example.com").await;
+
+    let tournament = create_tournament(
+        &state.conn,
+        CreateTournamentParams {
+            title: "leaderboards".to_string(),
+            scheduled_for: Utc::now().fixed_offset(),
+            description: String::new(),
+            text_options: None,
+            privacy: None,
+            race_duration_secs: None,
+            join_cutoff_secs: None,
+            inactivity_timeout_secs: None,
+            max_participants: None,
+            team_scoring: None,
+            rounds: None,
+            eliminations_per_round: None,
+            mode: None,
+            rematch_of: None,
+        },
+        &fast,
+    )
+    .await
+    .expect("Create tournament failed!");
+
+    let now = Utc::now();
+    // A race 40 days ago always falls before the current day, week and month.
+    let long_ago = now - TimeDelta::days(40);
+
+    let races = [
+        (&fast, 80, 95, RaceOutcome::Finished, now),
+        (&fast, 110, 95, RaceOutcome::Finished, long_ago),
+        (&steady, 100, 95, RaceOutcome::Finished, now),
+        (&steady, 100, 95, RaceOutcome::Finished, now),
+        (&tied, 100, 95, RaceOutcome::Finished, now),
+        (&tied, 200, 95, RaceOutcome::Dnf, now),
+        (&sloppy, 150, 80, RaceOutcome::Finished, now),
+    ];
+
+    for (user, speed, accuracy, outcome, completed_at) in races {
+        typing_history::ActiveModel {
+            user_id: Set(user.id.clone()),
+            tournament_id: Set(tournament.id.clone()),
+            accuracy: Set(accuracy),
+            speed: Set(speed),
+            completed_at: Set(completed_at.fixed_offset()),
+            placement: Set(1),
+            duration_ms: Set(None),
+            outcome: Set(outcome),
+            anonymous: Set(false),
+            ..Default::default()
+        }
+        .insert(&state.conn)
+        .await
+        .expect("Insert typing history failed!");
+    }
+
+    // Practice runs never rank, however fast.
+    let mut practice = TypingSessionSchema::new(
+        TournamentRoomMember::from_user(&tied, false, true),
+        "practice0002".to_string(),
+    );
+    practice.started_at = Some(now - TimeDelta::seconds(20));
+    practice.ended_at = Some(now);
+    practice.current_speed = 300.0;
+    practice.current_accuracy = 100.0;
+    record_practice_session(
+        &state.conn,
+        &practice,
+        &GeneratedText {
+            text: "practice text".to_string(),
+            passage: None,
+        },
+        7,
+        KeyStats::default(),
+        now,
+    )
+    .await
+    .expect("Record practice failed!");
+
+    let users = [&fast, &steady, &tied, &sloppy];
+
+    // Ties on score go to the member with more races.
+    let best = ranked(
+        state,
+        LeaderboardPeriod::AllTime,
+        LeaderboardMetric::Best,
+        &users,
+    )
+    .await;
+    assert_eq!(
+        best,
+        vec![
+            (fast.id.clone(), 110.0),
+            (steady.id.clone(), 100.0),
+            (tied.id.clone(), 100.0),
+        ]
+    );
+
+    let average = ranked(
+        state,
+        LeaderboardPeriod::AllTime,
+        LeaderboardMetric::Average,
+        &users,
+    )
+    .await;
+    assert_eq!(
+        average,
+        vec![
+            (steady.id.clone(), 100.0),
+            (tied.id.clone(), 100.0),
+            (fast.id.clone(), 95.0),
+        ]
+    );
+
+    for period in [
+        LeaderboardPeriod::Daily,
+        LeaderboardPeriod::Weekly,
+        LeaderboardPeriod::Monthly,
+    ] {
+        let best = ranked(state, period, LeaderboardMetric::Best, &users).await;
+        assert_eq!(
+            best,
+            vec![
+                (steady.id.clone(), 100.0),
+                (tied.id.clone(), 100.0),
+                (fast.id.clone(), 80.0),
+            ]
+        );
+    }
+
+    let limited = get_leaderboard(
+        state,
+        LeaderboardQuery {
+            period: Some(LeaderboardPeriod::AllTime),
+            metric: Some(LeaderboardMetric::Best),
+            min_accuracy: Some(MIN_ACCURACY),
+            limit: Some(1),
+        },
+    )
+    .await
+    .expect("Get leaderboard failed!");
+    assert_eq!(limited.entries.len(), 1);
+    assert_eq!(limited.entries[0].rank, 1);
+}
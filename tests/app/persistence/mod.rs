@@ -1,55 +1,88 @@
 use app::{
-    cache::{TournamentRegistry, TypingSessionRegistry},
+    cache::{LeaderboardCache, TournamentRegistry, TypingSessionRegistry},
     config::Config,
+    persistence::leaderboards::LEADERBOARD_CACHE_TTL,
     state::AppState,
 };
 use socketioxide::SocketIo;
-use tokio::sync::OnceCell;
 use utils::testing::setup_test_db;
 
+mod achievements;
+mod leaderboards;
+mod passages;
 mod tournament;
+mod typing_history;
 mod user;
 
-use tournament::test_tournament;
-use user::test_user;
+use achievements::test_achievements;
+use leaderboards::test_leaderboards;
+use passages::test_passages;
+use tournament::{test_clear_settings, test_search_tournaments, test_tournament};
+use typing_history::test_typing_history;
+use user::{test_user, test_user_email};
 
-static APP_STATE: OnceCell<AppState> = OnceCell::const_new();
+/// Each test gets a database of its own: an in-memory sqlite database lives
+/// only as long as its connection, which can't outlive the test's runtime.
+async fn get_app_state() -> AppState {
+    let config = Config::for_tests();
+    let conn = setup_test_db("sqlite::memory:")
+        .await
+        .expect("Set up db failed!");
+
+    let (_, socket_io) = SocketIo::new_layer();
 
-async fn get_app_state() -> &'static AppState {
-    if let Some(state) = APP_STATE.get() {
-        return state;
+    AppState {
+        conn,
+        config,
+        tournament_registry: TournamentRegistry::new(),
+        typing_session_registry: TypingSessionRegistry::new(),
+        leaderboard_cache: LeaderboardCache::new(LEADERBOARD_CACHE_TTL),
+        socket_io,
     }
-    APP_STATE
-        .get_or_init(async move || {
-            let config = Config::from_env().await;
-            let conn = setup_test_db("sqlite::memory:")
-                .await
-                .expect("Set up db failed!");
-
-            let (_, socket_io) = SocketIo::new_layer();
-
-            AppState {
-                conn,
-                config,
-                tournament_registry: TournamentRegistry::new(),
-                typing_session_registry: TypingSessionRegistry::new(),
-                socket_io,
-            }
-        })
-        .await
 }
 
 #[tokio::test]
 async fn user_main() {
-    let app_state = get_app_state().await;
+    let app_state = &get_app_state().await;
 
     test_user(app_state).await;
+    test_user_email(app_state).await;
 }
 
 #[tokio::test]
 async fn tournament_main() {
-    let app_state = get_app_state().await;
+    let app_state = &get_app_state().await;
 
     test_user(app_state).await;
     test_tournament(app_state).await;
+    test_clear_settings(app_state).await;
+    test_search_tournaments(app_state).await;
+}
+
+#[tokio::test]
+async fn typing_history_main() {
+    let app_state = &get_app_state().await;
+
+    test_typing_history(app_state).await;
+}
+
+#[tokio::test]
+async fn passages_main() {
+    let app_state = &get_app_state().await;
+
+    test_passages(app_state).await;
+}
+
+#[tokio::test]
+async fn leaderboards_main() {
+    let app_state = &get_app_state().await;
+
+    test_leaderboards(app_state).await;
+}
+
+#[tokio::test]
+async fn achievements_main() {
+    let app_state = &get_app_state().await;
+
+    test_achievements(app_state).await;
 }
@@ -0,0 +1,131 @@
+use app::persistence::passages::{
+    create_passage, get_passages, race_passage_ids, set_passage_status, update_passage,
+};
+use app::persistence::text::{PassageRevision, generate_text, regenerate_text};
+use app::state::AppState;
+use models::domains::sea_orm_active_enums::{PassageStatus, TextDifficulty, TextLanguage};
+use models::params::passage::{CreatePassageParams, UpdatePassageParams};
+use models::queries::passage::PassageQuery;
+use models::schemas::typing::TextOptions;
+
+pub(super) async fn test_passages(state: &AppState) {
+    let passage = create_passage(
+        &state.conn,
+        CreatePassageParams {
+            text: "It is a truth universally acknowledged, that a single man\n\
+                   in possession of a good fortune, must be in want of a wife."
+                .to_string(),
+            source: "Pride and Prejudice".to_string(),
+            author: Some("Jane Austen".to_string()),
+            tags: Some(vec!["Book Excerpt".to_string(), "classic".to_string()]),
+            difficulty: None,
+            language: None,
+        },
+    )
+    .await
+    .expect("Create passage failed!");
+
+    assert_eq!(passage.status, PassageStatus::Pending);
+    assert_eq!(passage.word_count, 23);
+    assert_eq!(passage.language, TextLanguage::En);
+    assert_eq!(passage.tags, vec!["book-excerpt", "classic"]);
+    assert!(!passage.text.contains('\n'));
+
+    // Pending passages are never raced.
+    let ids = race_passage_ids(&state.conn, 24, None, TextLanguage::En)
+        .await
+        .unwrap();
+    assert!(ids.is_empty());
+
+    set_passage_status(&state.conn, passage.id, PassageStatus::Approved)
+        .await
+        .unwrap()
+        .expect("Passage not found!");
+
+    let ids = race_passage_ids(&state.conn, 24, None, TextLanguage::En)
+        .await
+        .unwrap();
+    assert_eq!(ids, vec![passage.id]);
+    let ids = race_passage_ids(&state.conn, 100, None, TextLanguage::En)
+        .await
+        .unwrap();
+    assert!(ids.is_empty());
+    let ids = race_passage_ids(
+        &state.conn,
+        24,
+        Some(TextDifficulty::Hard),
+        TextLanguage::En,
+    )
+    .await
+    .unwrap();
+    assert!(ids.is_empty());
+    let ids = race_passage_ids(&state.conn, 24, None, TextLanguage::Fr)
+        .await
+        .unwrap();
+    assert!(ids.is_empty());
+
+    let options = TextOptions {
+        word_count: Some(24),
+        ..TextOptions::default()
+    };
+    let text = generate_text(&state.conn, options, 7).await;
+    assert_eq!(text.text, passage.text);
+    let drawn = PassageRevision {
+        id: passage.id,
+        revision: 1,
+    };
+    assert_eq!(text.passage, Some(drawn));
+    let regenerated = regenerate_text(&state.conn, options, 7, text.passage)
+        .await
+        .unwrap();
+    assert_eq!(regenerated, Some(text.text));
+
+    let tagged = |tag: &str| PassageQuery {
+        page: None,
+        limit: None,
+        status: None,
+        difficulty: None,
+        language: None,
+        tag: Some(tag.to_string()),
+    };
+    let found = get_passages(&state.conn, tagged("Book Excerpt"))
+        .await
+        .unwrap();
+    assert_eq!(found.total, 1);
+    let found = get_passages(&state.conn, tagged("book")).await.unwrap();
+    assert_eq!(found.total, 0);
+
+    // A new text needs approval again.
+    let updated = update_passage(
+        &state.conn,
+        passage.id,
+        UpdatePassageParams {
+            text: Some("The quick brown fox jumps over the lazy dog.".to_string()),
+            source: None,
+            author: None,
+            tags: None,
+            difficulty: None,
+            language: None,
+        },
+    )
+    .await
+    .unwrap()
+    .expect("Passage not found!");
+    assert_eq!(updated.status, PassageStatus::Pending);
+    assert_eq!(updated.word_count, 9);
+    assert_eq!(updated.revision, 2);
+
+    // Texts drawn from the old revision can't be regenerated any more.
+    let regenerated = regenerate_text(&state.conn, options, 7, Some(drawn))
+        .await
+        .unwrap();
+    assert_eq!(regenerated, None);
+
+    set_passage_status(&state.conn, passage.id, PassageStatus::Retired)
+        .await
+        .unwrap();
+    let ids = race_passage_ids(&state.conn, 9, None, TextLanguage::En)
+        .await
+        .unwrap();
+    assert!(ids.is_empty());
+}
@@ -3,8 +3,18 @@ use models::params::user::CreateUserParams;
 use models::schemas::user::UserSchema;
 use sea_orm::TryIntoModel;
 
-use app::persistence::tournaments::{create_tournament, get_tournament};
-use models::params::tournament::CreateTournamentParams;
+use app::persistence::text::{generate_text, race_seed};
+use app::persistence::tournament_bans::{ban_member, get_tournament_bans, is_banned, lift_ban};
+use app::persistence::tournaments::{
+    create_tournament, get_tournament, search_tournaments, update_tournament,
+};
+use chrono::Utc;
+use models::domains::sea_orm_active_enums::{RaceMode, TeamScoring, TextLanguage};
+use models::params::tournament::{CreateTournamentParams, UpdateTournamentParams};
+use models::queries::TournamentPaginationQuery;
+use models::schemas::tournament::TournamentSchema;
+use models::schemas::typing::TextOptions;
+use serde_json::json;
 
 pub(super) async fn test_tournament(state: &AppState) {
     let create_user_params = CreateUserParams {
@@ -20,14 +30,24 @@ pub(super) async fn test_tournament(state: &AppState) {
             .unwrap(),
     );
 
-    let create_tournament_params = CreateTournamentParams {
+    let create_tournament_params = |rematch_of| CreateTournamentParams {
         title: "title".to_string(),
-        scheduled_for: "2021-01-01 00:00:00".parse().unwrap(),
+        scheduled_for: "2021-01-01T00:00:00Z".parse().unwrap(),
         description: String::new(),
         text_options: None,
+        privacy: None,
+        race_duration_secs: None,
+        join_cutoff_secs: None,
+        inactivity_timeout_secs: None,
+        max_participants: None,
+        team_scoring: None,
+        rounds: None,
+        eliminations_per_round: None,
+        mode: None,
+        rematch_of,
     };
 
-    let tournament = create_tournament(&state.conn, create_tournament_params, &user)
+    let tournament = create_tournament(&state.conn, create_tournament_params(None), &user)
         .await
         .expect("Create tournament failed!");
 
@@ -37,4 +57,275 @@ pub(super) async fn test_tournament(state: &AppState) {
         .expect("Tournament not found");
 
     println!("Tournament created: {:?}", expected);
+    assert!(expected.text_seed.is_some());
+
+    // A tournament can only be rematched by its creator once it has ended.
+    let rematch_params = || create_tournament_params(Some(tournament.id.clone()));
+    assert!(
+        create_tournament(&state.conn, rematch_params(), &user)
+            .await
+            .is_err()
+    );
+
+    update_tournament(
+        state,
+        UpdateTournamentParams {
+            id: Some(tournament.id.clone()),
+            ended_at: Some(Some(Utc::now().fixed_offset())),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("Update tournament failed!");
+
+    // Whatever the rematch asks for, it races the texts of the original.
+    let rematch = create_tournament(
+        &state.conn,
+        CreateTournamentParams {
+            text_options: Some(TextOptions {
+                numbers: true,
+                ..Default::default()
+            }),
+            mode: Some(RaceMode::TimeAttack),
+            rounds: Some(3),
+            ..rematch_params()
+        },
+        &user,
+    )
+    .await
+    .expect("Create rematch failed!");
+    let rematch = TournamentSchema::from(rematch);
+    assert_eq!(rematch.text_seed, expected.text_seed);
+    assert_eq!(
+        rematch.text_options.map(TextOptions::to_value),
+        expected.text_options.map(TextOptions::to_value)
+    );
+    assert_eq!(rematch.mode, expected.mode);
+    assert_eq!(rematch.rounds, expected.rounds);
+
+    let text = |tournament: &TournamentSchema| {
+        generate_text(
+            &state.conn,
+            tournament.text_options.unwrap_or_default(),
+            race_seed(tournament.text_seed.unwrap(), 1, 0),
+        )
+    };
+    assert_eq!(text(&rematch).await, text(&expected).await);
+
+    let other = UserSchema::from(
+        create_user(
+            state,
+            CreateUserParams {
+                email: "rematcher".to_string(),
+                password: "password".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+        .try_into_model()
+        .unwrap(),
+    );
+    assert!(
+        create_tournament(&state.conn, rematch_params(), &other)
+            .await
+            .is_err()
+    );
+
+    ban_member(&state.conn, &tournament.id, "guest", Utc::now())
+        .await
+        .expect("Ban member failed!");
+    ban_member(&state.conn, &tournament.id, "guest", Utc::now())
+        .await
+        .expect("Banning twice failed!");
+
+    assert!(
+        is_banned(&state.conn, &tournament.id, "guest")
+            .await
+            .unwrap()
+    );
+    assert!(
+        !is_banned(&state.conn, &tournament.id, &user.id)
+            .await
+            .unwrap()
+    );
+
+    let bans = get_tournament_bans(&state.conn, &tournament.id)
+        .await
+        .unwrap()
+        .expect("Tournament not found");
+    assert_eq!(bans.bans.len(), 1);
+
+    assert!(
+        lift_ban(&state.conn, &tournament.id, "guest")
+            .await
+            .unwrap()
+    );
+    assert!(
+        !lift_ban(&state.conn, &tournament.id, "guest")
+            .await
+            .unwrap()
+    );
+    assert!(
+        !is_banned(&state.conn, &tournament.id, "guest")
+            .await
+            .unwrap()
+    );
+}
+
+/// Settings sent as `null` in an update are cleared; those left out are kept.
+pub(super) async fn test_clear_settings(state: &AppState) {
+    let user = UserSchema::from(
+        create_user(
+            state,
+            CreateUserParams {
+                email: "clearer".to_string(),
+                password: "password".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+        .try_into_model()
+        .unwrap(),
+    );
+
+    let tournament = create_tournament(
+        &state.conn,
+        CreateTournamentParams {
+            title: "clear".to_string(),
+            scheduled_for: Utc::now().fixed_offset(),
+            description: String::new(),
+            text_options: Some(TextOptions::default()),
+            privacy: None,
+            race_duration_secs: None,
+            join_cutoff_secs: None,
+            inactivity_timeout_secs: None,
+            max_participants: Some(8),
+            team_scoring: Some(TeamScoring::Sum),
+            rounds: None,
+            eliminations_per_round: None,
+            mode: None,
+            rematch_of: None,
+        },
+        &user,
+    )
+    .await
+    .expect("Create tournament failed!");
+
+    let update = |body: serde_json::Value| UpdateTournamentParams {
+        id: Some(tournament.id.clone()),
+        ..serde_json::from_value(body).expect("Parse update failed!")
+    };
+
+    let kept = update_tournament(state, update(json!({ "title": "kept" })))
+        .await
+        .expect("Update tournament failed!");
+    assert!(kept.text_options.is_some());
+    assert_eq!(kept.max_participants, Some(8));
+    assert_eq!(kept.team_scoring, Some(TeamScoring::Sum));
+
+    let cleared = update_tournament(state, update(json!({ "textOptions": null })))
+        .await
+        .expect("Update tournament failed!");
+    assert!(cleared.text_options.is_none());
+
+    let cleared = update_tournament(state, update(json!({ "maxParticipants": null })))
+        .await
+        .expect("Update tournament failed!");
+    assert_eq!(cleared.max_participants, None);
+
+    let cleared = update_tournament(state, update(json!({ "teamScoring": null })))
+        .await
+        .expect("Update tournament failed!");
+    assert_eq!(cleared.team_scoring, None);
+}
+
+/// Filtered listings report the total of the filtered set, not of every tournament.
+pub(super) async fn test_search_tournaments(state: &AppState) {
+    let user = UserSchema::from(
+        create_user(
+            state,
+            CreateUserParams {
+                email: "searcher".to_string(),
+                password: "password".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+        .try_into_model()
+        .unwrap(),
+    );
+
+    for (title, language) in [
+        ("Berlin sprint", TextLanguage::De),
+        ("Bremen sprint", TextLanguage::De),
+        ("Madrid sprint", TextLanguage::Es),
+    ] {
+        create_tournament(
+            &state.conn,
+            CreateTournamentParams {
+                title: title.to_string(),
+                scheduled_for: Utc::now().fixed_offset(),
+                description: String::new(),
+                text_options: Some(TextOptions {
+                    language,
+                    ..Default::default()
+                }),
+                privacy: None,
+                race_duration_secs: None,
+                join_cutoff_secs: None,
+                inactivity_timeout_secs: None,
+                max_participants: None,
+                team_scoring: None,
+                rounds: None,
+                eliminations_per_round: None,
+                mode: None,
+                rematch_of: None,
+            },
+            &user,
+        )
+        .await
+        .expect("Create tournament failed!");
+    }
+
+    let german = search_tournaments(
+        state,
+        TournamentPaginationQuery {
+            limit: Some(1),
+            language: Some(TextLanguage::De),
+            ..Default::default()
+        },
+        None,
+        None,
+    )
+    .await
+    .expect("Search tournaments failed!");
+    assert_eq!(german.total, 2);
+    assert_eq!(german.data.len(), 1);
+
+    let berlin = search_tournaments(
+        state,
+        TournamentPaginationQuery {
+            search: Some("Berlin%".to_string()),
+            ..Default::default()
+        },
+        None,
+        None,
+    )
+    .await
+    .expect("Search tournaments failed!");
+    assert_eq!(berlin.total, 1);
+    assert_eq!(berlin.data.len(), 1);
+
+    let by_creator = search_tournaments(
+        state,
+        TournamentPaginationQuery {
+            search: Some(user.username.clone()),
+            ..Default::default()
+        },
+        None,
+        None,
+    )
+    .await
+    .expect("Search tournaments failed!");
+    assert_eq!(by_creator.total, 3);
 }
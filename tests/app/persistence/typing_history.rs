@@ -0,0 +1,140 @@
+use app::core::KeyStats;
+use app::persistence::practice::record_practice_session;
+use app::persistence::race_replays::get_race_replay;
+use app::persistence::text::{GeneratedText, generate_text, race_seed};
+use app::persistence::tournaments::{create_tournament, get_tournament};
+use app::persistence::typing_history::{get_user_history, record_tournament_results};
+use app::{persistence::users::create_user, state::AppState};
+use chrono::{TimeDelta, Utc};
+use models::params::tournament::CreateTournamentParams;
+use models::params::user::CreateUserParams;
+use models::queries::typing_history::TypingHistoryQuery;
+use models::schemas::typing::{TextOptions, TypingSessionSchema};
+use models::schemas::user::{TournamentRoomMember, UserSchema};
+use sea_orm::TryIntoModel;
+
+pub(super) async fn test_typing_history(state: &AppState) {
+    let user = UserSchema::from(
+        create_user(
+            state,
+            CreateUserParams {
+                email: "history@example.com".to_string(),
+                password: "password".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+        .try_into_model()
+        .unwrap(),
+    );
+
+    let tournament = create_tournament(
+        &state.conn,
+        CreateTournamentParams {
+            title: "history".to_string(),
+            scheduled_for: Utc::now().fixed_offset(),
+            description: String::new(),
+            text_options: None,
+            privacy: None,
+            race_duration_secs: None,
+            join_cutoff_secs: None,
+            inactivity_timeout_secs: None,
+            max_participants: None,
+            team_scoring: None,
+            rounds: None,
+            eliminations_per_round: None,
+            mode: None,
+            rematch_of: None,
+        },
+        &user,
+    )
+    .await
+    .expect("Create tournament failed!");
+
+    let now = Utc::now();
+
+    let mut finished = TypingSessionSchema::new(
+        TournamentRoomMember::from_user(&user, false, true),
+        tournament.id.clone(),
+    );
+    finished.started_at = Some(now - TimeDelta::seconds(30));
+    finished.ended_at = Some(now);
+    finished.current_speed = 72.4;
+    finished.current_accuracy = 96.0;
+
+    let guest = TypingSessionSchema::new(
+        TournamentRoomMember {
+            id: "guest".to_string(),
+            user: None,
+            participant: true,
+            rating: None,
+            user_id: None,
+            team: None,
+        },
+        tournament.id.clone(),
+    );
+
+    let recorded = record_tournament_results(&state.conn, &tournament.id, &[finished, guest], now)
+        .await
+        .expect("Record results failed!");
+    assert_eq!(recorded, 1);
+
+    let history = get_user_history(&state.conn, &user.id, TypingHistoryQuery::default(), false)
+        .await
+        .expect("Get history failed!");
+
+    assert_eq!(history.total, 1);
+    assert_eq!(history.data[0].speed, 72);
+    assert_eq!(history.data[0].placement, 1);
+    assert_eq!(history.data[0].duration_ms, Some(30_000));
+
+    let mut practice = TypingSessionSchema::new(
+        TournamentRoomMember::from_user(&user, false, true),
+        "practice0001".to_string(),
+    );
+    practice.started_at = Some(now - TimeDelta::seconds(20));
+    practice.ended_at = Some(now);
+    practice.current_speed = 80.0;
+
+    let saved = record_practice_session(
+        &state.conn,
+        &practice,
+        &GeneratedText {
+            text: "practice text".to_string(),
+            passage: None,
+        },
+        7,
+        KeyStats::default(),
+        now,
+    )
+    .await
+    .expect("Record practice failed!");
+    assert!(saved);
+
+    let practice_tournament = get_tournament(&state.conn, "practice0001".to_string())
+        .await
+        .expect("Get practice tournament failed!")
+        .expect("Practice tournament not found!");
+    assert!(practice_tournament.practice);
+    assert!(practice_tournament.ended_at.is_some());
+
+    // The text isn't stored, the replay regenerates it from the seed.
+    let replay = get_race_replay(&state.conn, "practice0001")
+        .await
+        .expect("Get replay failed!")
+        .expect("Practice replay not found!");
+    let expected = generate_text(&state.conn, TextOptions::default(), race_seed(7, 1, 0)).await;
+    assert_eq!(replay.text, Some(expected.text));
+
+    let history = get_user_history(&state.conn, &user.id, TypingHistoryQuery::default(), false)
+        .await
+        .expect("Get history failed!");
+
+    assert_eq!(history.total, 2);
+    assert!(
+        history
+            .data
+            .iter()
+            .any(|entry| entry.tournament_id == "practice0001" && entry.placement == 0)
+    );
+}
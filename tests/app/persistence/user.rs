@@ -2,6 +2,7 @@ use app::{
     persistence::users::{create_user, get_user},
     state::AppState,
 };
+use models::domains::sea_orm_active_enums::UserRole;
 use models::params::user::CreateUserParams;
 
 pub(super) async fn test_user(state: &AppState) {
@@ -20,3 +21,29 @@ pub(super) async fn test_user(state: &AppState) {
 
     assert_eq!(user, expected);
 }
+
+pub(super) async fn test_user_email(state: &AppState) {
+    let user = create_user(
+        state,
+        CreateUserParams {
+            password: "password".to_string(),
+            email: " Mixed.Case@Example.com ".to_string(),
+        },
+    )
+    .await
+    .expect("Create user failed!");
+
+    assert_eq!(user.email, "mixed.case@example.com");
+    assert_eq!(user.role, UserRole::User);
+
+    let duplicate = create_user(
+        state,
+        CreateUserParams {
+            password: "password".to_string(),
+            email: "MIXED.CASE@example.com".to_string(),
+        },
+    )
+    .await;
+
+    assert!(duplicate.is_err());
+}
@@ -1,9 +1,13 @@
-use sea_orm::{Database, DatabaseConnection, DbErr};
+use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbErr};
 
 use crate::migrate;
 
 pub async fn setup_test_db(db_url: &str) -> Result<DatabaseConnection, DbErr> {
-    let db = Database::connect(db_url).await?;
+    // Every connection to `sqlite::memory:` opens a database of its own.
+    let mut opt = ConnectOptions::new(db_url);
+    opt.max_connections(1);
+
+    let db = Database::connect(opt).await?;
     migrate(&db).await?;
     Ok(db)
 }